@@ -0,0 +1,338 @@
+use serde::{Deserialize, Serialize};
+
+use crate::blocks::{ButtonStyle, Confirm, OptionObject, Text};
+
+/// An interactive element, used in section, actions and input blocks.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Element {
+    Button(Button),
+    StaticSelect(StaticSelect),
+    Datepicker(DatePicker),
+    Overflow(Overflow),
+    PlainTextInput(PlainTextInput),
+    Image(ImageElement),
+}
+
+/// A button element.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#button
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Button {
+    /// The text on the button, in plain text.
+    pub text: Text,
+    /// An identifier for the action triggered when the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// A URL to load in the user's browser when the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The value sent along with the interaction payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The color scheme of the button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    /// A confirmation dialog shown after the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+    /// A label for longer descriptive text about the button, for screen readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility_label: Option<String>,
+}
+
+impl Button {
+    /// Create a new button with the text and the action ID.
+    pub fn new(text: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            text: Text::plain(text),
+            action_id: Some(action_id.into()),
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+            accessibility_label: None,
+        }
+    }
+
+    /// Set the URL.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Set the value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Set the style.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Set the confirmation dialog.
+    pub fn confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Set the accessibility label.
+    pub fn accessibility_label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
+}
+
+/// A select menu with a static list of options.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#static_select
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StaticSelect {
+    /// An identifier for the action triggered when a menu option is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The placeholder text shown on the menu, in plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// The options of the menu.
+    pub options: Vec<OptionObject>,
+    /// The option selected when the menu initially loads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    /// A confirmation dialog shown after a menu item is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+    /// Whether the element will be set to autofocus within the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl StaticSelect {
+    /// Create a new select menu with the action ID and the options.
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Self {
+            action_id: Some(action_id.into()),
+            placeholder: None,
+            options,
+            initial_option: None,
+            confirm: None,
+            focus_on_load: None,
+        }
+    }
+
+    /// Set the placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    /// Set the initially selected option.
+    pub fn initial_option(mut self, option: OptionObject) -> Self {
+        self.initial_option = Some(option);
+        self
+    }
+
+    /// Set the confirmation dialog.
+    pub fn confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A date picker.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#datepicker
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DatePicker {
+    /// An identifier for the action triggered when a date is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The initially selected date in `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    /// The placeholder text shown on the picker, in plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// A confirmation dialog shown after a date is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+    /// Whether the element will be set to autofocus within the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl DatePicker {
+    /// Create a new date picker with the action ID.
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: Some(action_id.into()),
+            initial_date: None,
+            placeholder: None,
+            confirm: None,
+            focus_on_load: None,
+        }
+    }
+
+    /// Set the initially selected date in `YYYY-MM-DD`.
+    pub fn initial_date(mut self, date: impl Into<String>) -> Self {
+        self.initial_date = Some(date.into());
+        self
+    }
+
+    /// Set the placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+}
+
+/// An overflow menu, which is a compact button with a menu of options.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#overflow
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Overflow {
+    /// An identifier for the action triggered when a menu option is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The options of the menu, up to 5.
+    pub options: Vec<OptionObject>,
+    /// A confirmation dialog shown after a menu item is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Overflow {
+    /// Create a new overflow menu with the action ID and the options.
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Self {
+            action_id: Some(action_id.into()),
+            options,
+            confirm: None,
+        }
+    }
+
+    /// Set the confirmation dialog.
+    pub fn confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A plain-text input, used in input blocks.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#input
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlainTextInput {
+    /// An identifier for the input value when the parent modal is submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The initial value in the input when it is loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    /// Whether the input will be a single line (`false`) or a larger textarea (`true`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    /// The minimum length of input that the user must provide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    /// The maximum length of input that the user can provide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+    /// The placeholder text shown in the input, in plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+}
+
+impl PlainTextInput {
+    /// Create a new plain-text input with the action ID.
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: Some(action_id.into()),
+            initial_value: None,
+            multiline: None,
+            min_length: None,
+            max_length: None,
+            placeholder: None,
+        }
+    }
+
+    /// Make the input a larger textarea.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = Some(multiline);
+        self
+    }
+
+    /// Set the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.initial_value = Some(value.into());
+        self
+    }
+
+    /// Set the placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+}
+
+/// An image element, used in section and context blocks. Not to be confused with the image block.
+///
+/// See: https://api.slack.com/reference/block-kit/block-elements#image
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImageElement {
+    /// The URL of the image.
+    pub image_url: String,
+    /// A plain-text summary of the image.
+    pub alt_text: String,
+}
+
+impl ImageElement {
+    /// Create a new image element.
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+        }
+    }
+}
+
+impl From<Button> for Element {
+    fn from(value: Button) -> Self {
+        Element::Button(value)
+    }
+}
+
+impl From<StaticSelect> for Element {
+    fn from(value: StaticSelect) -> Self {
+        Element::StaticSelect(value)
+    }
+}
+
+impl From<DatePicker> for Element {
+    fn from(value: DatePicker) -> Self {
+        Element::Datepicker(value)
+    }
+}
+
+impl From<Overflow> for Element {
+    fn from(value: Overflow) -> Self {
+        Element::Overflow(value)
+    }
+}
+
+impl From<PlainTextInput> for Element {
+    fn from(value: PlainTextInput) -> Self {
+        Element::PlainTextInput(value)
+    }
+}
+
+impl From<ImageElement> for Element {
+    fn from(value: ImageElement) -> Self {
+        Element::Image(value)
+    }
+}
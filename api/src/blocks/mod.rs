@@ -0,0 +1,590 @@
+mod elements;
+mod rich_text;
+mod text;
+
+pub use elements::{
+    Button, DatePicker, Element, ImageElement, Overflow, PlainTextInput, StaticSelect,
+};
+pub use rich_text::{ListStyle, RichTextElement, RichTextInline, TextStyle};
+use serde::{Deserialize, Serialize};
+pub use text::{ButtonStyle, Confirm, OptionObject, Text};
+
+/// A layout block of a message. Build a list of them with [`Blocks`].
+///
+/// See: https://api.slack.com/reference/block-kit/blocks
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Section(Section),
+    Header(Header),
+    Divider(Divider),
+    Context(Context),
+    Actions(Actions),
+    Image(Image),
+    Input(Input),
+    RichText(RichText),
+}
+
+/// A section block, which displays text, optionally with fields and an accessory element.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#section
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Section {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// The text of the section. Either `text` or `fields` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    /// Texts shown in two columns, up to 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    /// An element shown next to the text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Element>,
+}
+
+impl Section {
+    /// Create a new section with the text.
+    pub fn new(text: Text) -> Self {
+        Self { text: Some(text), ..Default::default() }
+    }
+
+    /// Add a field.
+    pub fn field(mut self, field: Text) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    /// Set the accessory element.
+    pub fn accessory(mut self, element: impl Into<Element>) -> Self {
+        self.accessory = Some(element.into());
+        self
+    }
+
+    /// Set the block ID.
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+/// A header block, which displays plain text in a larger, bold font.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#header
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// The text of the header, in plain text.
+    pub text: Text,
+}
+
+/// A divider block.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#divider
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Divider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A context block, which displays small texts and images.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#context
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Context {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Texts and images, up to 10.
+    pub elements: Vec<ContextElement>,
+}
+
+impl Context {
+    /// Add a text.
+    pub fn text(mut self, text: Text) -> Self {
+        self.elements.push(ContextElement::Text(text));
+        self
+    }
+
+    /// Add an image.
+    pub fn image(mut self, image: ImageElement) -> Self {
+        self.elements.push(ContextElement::Image(image));
+        self
+    }
+}
+
+/// An element of a context block, which is either a text or an image element.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextElement {
+    Image(ImageElement),
+    #[serde(untagged)]
+    Text(Text),
+}
+
+/// An actions block, which holds interactive elements.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#actions
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Actions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Interactive elements, up to 25.
+    pub elements: Vec<Element>,
+}
+
+impl Actions {
+    /// Add an element.
+    pub fn element(mut self, element: impl Into<Element>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+/// An image block. Not to be confused with the image element.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#image
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Image {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// The URL of the image.
+    pub image_url: String,
+    /// A plain-text summary of the image.
+    pub alt_text: String,
+    /// The title of the image, in plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+}
+
+impl Image {
+    /// Create a new image block.
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            block_id: None,
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+            title: None,
+        }
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(Text::plain(title));
+        self
+    }
+}
+
+/// An input block, which collects information from users.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#input
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Input {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// The label of the input, in plain text.
+    pub label: Text,
+    /// The input element.
+    pub element: Element,
+    /// A hint shown below the input, in plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    /// Whether the input may be empty when the user submits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    /// Whether the element dispatches a `block_actions` payload when used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+}
+
+impl Input {
+    /// Create a new input block with the label and the element.
+    pub fn new(label: impl Into<String>, element: impl Into<Element>) -> Self {
+        Self {
+            block_id: None,
+            label: Text::plain(label),
+            element: element.into(),
+            hint: None,
+            optional: None,
+            dispatch_action: None,
+        }
+    }
+
+    /// Set the hint.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(Text::plain(hint));
+        self
+    }
+
+    /// Set whether the input may be empty.
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+}
+
+/// A rich text block, which is what the Slack client produces for messages typed by users.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#rich_text
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RichText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    pub elements: Vec<RichTextElement>,
+}
+
+impl RichText {
+    /// Add an element.
+    pub fn element(mut self, element: RichTextElement) -> Self {
+        self.elements.push(element);
+        self
+    }
+}
+
+/// A fluent builder of a list of blocks, e.g.
+///
+/// ```
+/// use slack_api::blocks::{Blocks, Button, Section, Text};
+///
+/// let blocks = Blocks::new()
+///     .header("Deploy finished")
+///     .section(Section::new(Text::mrkdwn("*api* is now `v1.2.3`")).accessory(Button::new("Open", "open")))
+///     .divider()
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Blocks {
+    inner: Vec<Block>,
+}
+
+impl Blocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a block.
+    pub fn block(mut self, block: impl Into<Block>) -> Self {
+        self.inner.push(block.into());
+        self
+    }
+
+    /// Add a section block.
+    pub fn section(self, section: Section) -> Self {
+        self.block(section)
+    }
+
+    /// Add a header block with the plain text.
+    pub fn header(self, text: impl Into<String>) -> Self {
+        self.block(Header { block_id: None, text: Text::plain(text) })
+    }
+
+    /// Add a divider block.
+    pub fn divider(self) -> Self {
+        self.block(Divider::default())
+    }
+
+    /// Add a context block.
+    pub fn context(self, context: Context) -> Self {
+        self.block(context)
+    }
+
+    /// Add an actions block.
+    pub fn actions(self, actions: Actions) -> Self {
+        self.block(actions)
+    }
+
+    /// Add an image block.
+    pub fn image(self, image: Image) -> Self {
+        self.block(image)
+    }
+
+    /// Add an input block.
+    pub fn input(self, input: Input) -> Self {
+        self.block(input)
+    }
+
+    /// Add a rich text block.
+    pub fn rich_text(self, rich_text: RichText) -> Self {
+        self.block(rich_text)
+    }
+
+    /// Returns the blocks, to be set to `blocks` of a `chat` request.
+    pub fn build(self) -> Vec<Block> {
+        self.inner
+    }
+}
+
+impl From<Blocks> for Vec<Block> {
+    fn from(value: Blocks) -> Self {
+        value.inner
+    }
+}
+
+macro_rules! impl_from_for_block {
+    ($($variant:ident),+) => {
+        $(
+            impl From<$variant> for Block {
+                fn from(value: $variant) -> Self {
+                    Block::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_for_block!(Section, Header, Divider, Context, Actions, Image, Input, RichText);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Deserialize the JSON into a block, and assert that it serializes back to the same JSON.
+    fn round_trip(json: Value) -> Block {
+        let block: Block = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&block).unwrap(), json);
+        block
+    }
+
+    #[test]
+    fn section_with_accessory() {
+        let block = round_trip(json!({
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": "This is a section block with an accessory image."
+            },
+            "accessory": {
+                "type": "image",
+                "image_url": "https://pbs.twimg.com/profile_images/625633822235693056/lNGUneLX_400x400.jpg",
+                "alt_text": "cute cat"
+            }
+        }));
+        let Block::Section(section) = block else { panic!("{block:?}") };
+        assert!(matches!(section.accessory, Some(Element::Image(_))));
+
+        round_trip(json!({
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "This is a section block with a button." },
+            "fields": [
+                { "type": "mrkdwn", "text": "*Priority*" },
+                { "type": "plain_text", "text": "High", "emoji": true }
+            ],
+            "accessory": {
+                "type": "button",
+                "text": { "type": "plain_text", "text": "Click Me", "emoji": true },
+                "value": "click_me_123",
+                "action_id": "button-action",
+                "style": "primary"
+            }
+        }));
+    }
+
+    #[test]
+    fn context_with_image_and_mrkdwn() {
+        let block = round_trip(json!({
+            "type": "context",
+            "elements": [
+                {
+                    "type": "image",
+                    "image_url": "https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg",
+                    "alt_text": "images"
+                },
+                { "type": "mrkdwn", "text": "Location: *Dogpatch*" },
+                { "type": "plain_text", "text": "Author: K A Applegate", "emoji": true }
+            ]
+        }));
+        let Block::Context(context) = block else { panic!("{block:?}") };
+        assert_eq!(
+            context.elements,
+            vec![
+                ContextElement::Image(ImageElement::new(
+                    "https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg",
+                    "images"
+                )),
+                ContextElement::Text(Text::mrkdwn("Location: *Dogpatch*")),
+                ContextElement::Text(Text::PlainText {
+                    text: "Author: K A Applegate".to_string(),
+                    emoji: Some(true)
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn actions() {
+        round_trip(json!({
+            "type": "actions",
+            "block_id": "actions1",
+            "elements": [
+                {
+                    "type": "static_select",
+                    "placeholder": { "type": "plain_text", "text": "Which witch is the witchiest witch?" },
+                    "action_id": "select_2",
+                    "options": [
+                        { "text": { "type": "plain_text", "text": "Matilda" }, "value": "matilda" },
+                        { "text": { "type": "plain_text", "text": "Glinda" }, "value": "glinda" }
+                    ]
+                },
+                {
+                    "type": "datepicker",
+                    "initial_date": "1990-04-28",
+                    "placeholder": { "type": "plain_text", "text": "Select a date" },
+                    "action_id": "datepicker-action"
+                },
+                {
+                    "type": "overflow",
+                    "options": [
+                        {
+                            "text": { "type": "plain_text", "text": "Docs" },
+                            "value": "docs",
+                            "url": "https://api.slack.com"
+                        }
+                    ],
+                    "action_id": "overflow-action"
+                },
+                {
+                    "type": "button",
+                    "text": { "type": "plain_text", "text": "Delete" },
+                    "value": "delete",
+                    "action_id": "button_1",
+                    "style": "danger",
+                    "confirm": {
+                        "title": { "type": "plain_text", "text": "Are you sure?" },
+                        "text": { "type": "mrkdwn", "text": "Wouldn't you prefer a good game of _chess_?" },
+                        "confirm": { "type": "plain_text", "text": "Do it" },
+                        "deny": { "type": "plain_text", "text": "Stop, I've changed my mind!" },
+                        "style": "danger"
+                    }
+                }
+            ]
+        }));
+    }
+
+    #[test]
+    fn input() {
+        round_trip(json!({
+            "type": "input",
+            "element": {
+                "type": "plain_text_input",
+                "action_id": "plain_text_input-action",
+                "multiline": true,
+                "max_length": 500
+            },
+            "label": { "type": "plain_text", "text": "Label", "emoji": true },
+            "hint": { "type": "plain_text", "text": "Hint" },
+            "optional": true
+        }));
+    }
+
+    #[test]
+    fn header_and_divider() {
+        round_trip(json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": "A Heartfelt Header" }
+        }));
+        round_trip(json!({ "type": "divider" }));
+        round_trip(json!({ "type": "divider", "block_id": "divider1" }));
+    }
+
+    #[test]
+    fn image() {
+        round_trip(json!({
+            "type": "image",
+            "title": { "type": "plain_text", "text": "Please enjoy this photo of a kitten" },
+            "block_id": "image4",
+            "image_url": "http://placekitten.com/500/500",
+            "alt_text": "An incredibly cute kitten."
+        }));
+    }
+
+    #[test]
+    fn rich_text() {
+        let block = round_trip(json!({
+            "type": "rich_text",
+            "block_id": "Vrzsu",
+            "elements": [
+                {
+                    "type": "rich_text_section",
+                    "elements": [
+                        { "type": "text", "text": "Hello there, " },
+                        { "type": "text", "text": "I am a bold rich text block!", "style": { "bold": true } },
+                        { "type": "user", "user_id": "U0123ABCD" },
+                        { "type": "channel", "channel_id": "C0123ABCD" },
+                        { "type": "usergroup", "usergroup_id": "S0123ABCD" },
+                        { "type": "broadcast", "range": "here" },
+                        { "type": "emoji", "name": "basketball", "unicode": "1f3c0" },
+                        { "type": "link", "url": "https://api.slack.com", "text": "docs", "style": { "italic": true } }
+                    ]
+                },
+                {
+                    "type": "rich_text_list",
+                    "style": "bullet",
+                    "indent": 0,
+                    "border": 1,
+                    "elements": [
+                        { "type": "rich_text_section", "elements": [{ "type": "text", "text": "huddles" }] },
+                        { "type": "rich_text_section", "elements": [{ "type": "text", "text": "canvases" }] }
+                    ]
+                },
+                {
+                    "type": "rich_text_list",
+                    "style": "ordered",
+                    "indent": 1,
+                    "offset": 2,
+                    "elements": [
+                        { "type": "rich_text_section", "elements": [{ "type": "text", "text": "nested" }] }
+                    ]
+                },
+                {
+                    "type": "rich_text_preformatted",
+                    "elements": [{ "type": "text", "text": "fn main() {}" }],
+                    "border": 0
+                },
+                {
+                    "type": "rich_text_quote",
+                    "elements": [{ "type": "text", "text": "What we need is good examples." }]
+                }
+            ]
+        }));
+        let Block::RichText(rich_text) = block else { panic!("{block:?}") };
+        assert!(matches!(
+            rich_text.elements[2],
+            RichTextElement::List {
+                style: ListStyle::Ordered,
+                indent: Some(1),
+                offset: Some(2),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn builder() {
+        let blocks = Blocks::new()
+            .header("Deploy finished")
+            .section(
+                Section::new(Text::mrkdwn("*api* is now `v1.2.3`"))
+                    .accessory(Button::new("Open", "open")),
+            )
+            .divider()
+            .build();
+        assert_eq!(
+            serde_json::to_value(&blocks).unwrap(),
+            json!([
+                { "type": "header", "text": { "type": "plain_text", "text": "Deploy finished" } },
+                {
+                    "type": "section",
+                    "text": { "type": "mrkdwn", "text": "*api* is now `v1.2.3`" },
+                    "accessory": {
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Open" },
+                        "action_id": "open"
+                    }
+                },
+                { "type": "divider" }
+            ])
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::{ChannelId, UserId, UsergroupId};
+
+/// A top-level element of a rich text block.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#rich_text
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum RichTextElement {
+    /// A paragraph of inline elements.
+    #[serde(rename = "rich_text_section")]
+    Section { elements: Vec<RichTextInline> },
+    /// A list, whose items are `Section`s.
+    #[serde(rename = "rich_text_list")]
+    List {
+        style: ListStyle,
+        elements: Vec<RichTextElement>,
+        /// The nesting level of the list, starting from 0 for a top-level list.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        indent: Option<u8>,
+        /// The number of items to offset the numbering by, e.g. `2` to start an ordered list at 3.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        offset: Option<u32>,
+        /// The number of pixels of the border.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        border: Option<u8>,
+    },
+    /// A code block.
+    #[serde(rename = "rich_text_preformatted")]
+    Preformatted {
+        elements: Vec<RichTextInline>,
+        /// The number of pixels of the border.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        border: Option<u8>,
+    },
+    /// A quote block.
+    #[serde(rename = "rich_text_quote")]
+    Quote { elements: Vec<RichTextInline> },
+}
+
+impl RichTextElement {
+    /// Create a paragraph.
+    pub fn section(elements: Vec<RichTextInline>) -> Self {
+        RichTextElement::Section { elements }
+    }
+
+    /// Create a list, each item of which is a paragraph.
+    pub fn list(style: ListStyle, items: Vec<Vec<RichTextInline>>) -> Self {
+        RichTextElement::List {
+            style,
+            elements: items.into_iter().map(Self::section).collect(),
+            indent: None,
+            offset: None,
+            border: None,
+        }
+    }
+
+    /// Create a code block.
+    pub fn preformatted(elements: Vec<RichTextInline>) -> Self {
+        RichTextElement::Preformatted { elements, border: None }
+    }
+
+    /// Create a quote block.
+    pub fn quote(elements: Vec<RichTextInline>) -> Self {
+        RichTextElement::Quote { elements }
+    }
+}
+
+/// The style of a rich text list.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListStyle {
+    Bullet,
+    Ordered,
+}
+
+/// An inline element of a rich text section, preformatted or quote.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichTextInline {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<TextStyle>,
+    },
+    Link {
+        url: String,
+        /// The text shown instead of the URL.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<TextStyle>,
+    },
+    User {
+        user_id: UserId,
+    },
+    Channel {
+        channel_id: ChannelId,
+    },
+    Usergroup {
+        usergroup_id: UsergroupId,
+    },
+    Emoji {
+        /// The name of the emoji, without colons.
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unicode: Option<String>,
+    },
+    /// A special mention, i.e. `here`, `channel` or `everyone`.
+    Broadcast {
+        range: String,
+    },
+}
+
+impl RichTextInline {
+    /// Create a text without style.
+    pub fn text(text: impl Into<String>) -> Self {
+        RichTextInline::Text { text: text.into(), style: None }
+    }
+
+    /// Create a text with the style.
+    pub fn styled(text: impl Into<String>, style: TextStyle) -> Self {
+        RichTextInline::Text { text: text.into(), style: Some(style) }
+    }
+
+    /// Create a link, optionally with the text shown instead of the URL.
+    pub fn link(url: impl Into<String>, text: Option<String>) -> Self {
+        RichTextInline::Link { url: url.into(), text, style: None }
+    }
+
+    /// Create a user mention.
+    pub fn user(user_id: UserId) -> Self {
+        RichTextInline::User { user_id }
+    }
+
+    /// Create a channel mention.
+    pub fn channel(channel_id: ChannelId) -> Self {
+        RichTextInline::Channel { channel_id }
+    }
+
+    /// Create a usergroup mention.
+    pub fn usergroup(usergroup_id: UsergroupId) -> Self {
+        RichTextInline::Usergroup { usergroup_id }
+    }
+
+    /// Create an emoji, e.g. `thumbsup`.
+    pub fn emoji(name: impl Into<String>) -> Self {
+        RichTextInline::Emoji { name: name.into(), unicode: None }
+    }
+
+    /// Create a special mention, e.g. `here`.
+    pub fn broadcast(range: impl Into<String>) -> Self {
+        RichTextInline::Broadcast { range: range.into() }
+    }
+}
+
+/// The style of a rich text.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strike: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<bool>,
+}
+
+impl TextStyle {
+    /// Make the text bold.
+    pub fn bold(mut self) -> Self {
+        self.bold = Some(true);
+        self
+    }
+
+    /// Make the text italic.
+    pub fn italic(mut self) -> Self {
+        self.italic = Some(true);
+        self
+    }
+
+    /// Make the text strikethrough.
+    pub fn strike(mut self) -> Self {
+        self.strike = Some(true);
+        self
+    }
+
+    /// Make the text inline code.
+    pub fn code(mut self) -> Self {
+        self.code = Some(true);
+        self
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+/// A text object, which is either plain text or mrkdwn.
+///
+/// See: https://api.slack.com/reference/block-kit/composition-objects#text
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        /// Whether emojis in the text should be escaped into the colon emoji format.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        /// When set to `true`, URLs, mentions and channel names are not auto-converted.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    /// Create a `plain_text` text object.
+    pub fn plain(text: impl Into<String>) -> Self {
+        Text::PlainText { text: text.into(), emoji: None }
+    }
+
+    /// Create a `mrkdwn` text object.
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Text::Mrkdwn { text: text.into(), verbatim: None }
+    }
+
+    /// Returns the text.
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } | Text::Mrkdwn { text, .. } => text,
+        }
+    }
+}
+
+/// An option object, which is an item of select menus and overflow menus.
+///
+/// See: https://api.slack.com/reference/block-kit/composition-objects#option
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OptionObject {
+    /// The text shown in the option.
+    pub text: Text,
+    /// The value sent to your app when the option is chosen.
+    pub value: String,
+    /// A line of descriptive text shown below the `text`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    /// A URL to load in the user's browser when the option is clicked, only for overflow menus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl OptionObject {
+    /// Create a new option with the plain text and the value.
+    pub fn new(text: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            text: Text::plain(text),
+            value: value.into(),
+            description: None,
+            url: None,
+        }
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: Text) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Set the URL.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+/// A confirmation dialog, which asks the user to confirm an interaction.
+///
+/// See: https://api.slack.com/reference/block-kit/composition-objects#confirm
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Confirm {
+    /// The header of the dialog, in plain text.
+    pub title: Text,
+    /// The explanatory text of the dialog.
+    pub text: Text,
+    /// The text of the button to confirm, in plain text.
+    pub confirm: Text,
+    /// The text of the button to cancel, in plain text.
+    pub deny: Text,
+    /// The color scheme of the confirm button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl Confirm {
+    /// Create a new confirmation dialog.
+    pub fn new(
+        title: impl Into<String>,
+        text: Text,
+        confirm: impl Into<String>,
+        deny: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: Text::plain(title),
+            text,
+            confirm: Text::plain(confirm),
+            deny: Text::plain(deny),
+            style: None,
+        }
+    }
+
+    /// Set the style of the confirm button.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// The color scheme of a button.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    /// Green.
+    Primary,
+    /// Red.
+    Danger,
+}
@@ -1,15 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{bots::BotsQuery, request::Request, response::Response};
+use crate::{bots::BotsQuery, id::BotId, request::Request, response::Response};
 
-/// A request for `users.info` API.
+/// A request for `bots.info` API.
 ///
-/// See: https://api.slack.com/methods/users.info
+/// See: https://api.slack.com/methods/bots.info
 #[derive(Serialize, Debug, Clone)]
 pub struct Info<'a> {
-    /// User ID to get info on
+    /// Bot ID to get info on
     #[serde(rename = "bot")]
-    pub id: &'a str,
+    pub id: &'a BotId,
 }
 
 impl BotsQuery for Info<'_> {}
@@ -25,6 +25,8 @@ impl Request for Info<'_> {
 #[derive(Deserialize, Debug, Clone)]
 pub struct BotsInfo {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     /// The bot object.
     pub bot: Option<Bot>,
 }
@@ -32,6 +34,10 @@ impl Response for BotsInfo {
     fn is_ok(&self) -> bool {
         self.ok
     }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -1,8 +1,8 @@
 mod info;
 
-pub use info::Info;
+pub use info::{Bot, Info};
 
 use crate::request::Request;
 
-/// A marker trait which denotes a request for the `users` API.
+/// A marker trait which denotes a request for the `bots` API.
 pub trait BotsQuery: Request {}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chat::ChatQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.delete` API.
+///
+/// See: https://api.slack.com/methods/chat.delete
+#[derive(Serialize, Debug, Clone)]
+pub struct Delete<'a> {
+    /// Channel containing the message to be deleted.
+    pub channel: &'a ChannelId,
+    /// Timestamp of the message to be deleted.
+    pub ts: Ts,
+}
+
+impl ChatQuery for Delete<'_> {}
+
+impl Request for Delete<'_> {
+    type Response = ChatDelete;
+
+    fn path(&self) -> &'static str {
+        "chat.delete"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatDelete {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel of the deleted message.
+    pub channel: Option<ChannelId>,
+    /// The timestamp of the deleted message.
+    pub ts: Option<Ts>,
+}
+
+impl Response for ChatDelete {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
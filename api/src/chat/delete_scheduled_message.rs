@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chat::ChatQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `chat.deleteScheduledMessage` API.
+///
+/// See: https://api.slack.com/methods/chat.deleteScheduledMessage
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteScheduledMessage<'a> {
+    /// The channel the scheduled message is posting to.
+    pub channel: &'a ChannelId,
+    /// The ID of the scheduled message, returned from `chat.scheduleMessage`.
+    pub scheduled_message_id: &'a str,
+}
+
+impl ChatQuery for DeleteScheduledMessage<'_> {}
+
+impl Request for DeleteScheduledMessage<'_> {
+    type Response = ChatDeleteScheduledMessage;
+
+    fn path(&self) -> &'static str {
+        "chat.deleteScheduledMessage"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatDeleteScheduledMessage {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ChatDeleteScheduledMessage {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
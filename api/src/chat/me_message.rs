@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chat::ChatQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.meMessage` API, which shares a me message (`/me`) into a channel.
+///
+/// See: https://api.slack.com/methods/chat.meMessage
+#[derive(Serialize, Debug, Clone)]
+pub struct MeMessage<'a> {
+    /// Channel to send message to.
+    pub channel: &'a ChannelId,
+    /// Text of the message to send.
+    pub text: &'a str,
+}
+
+impl ChatQuery for MeMessage<'_> {}
+
+impl Request for MeMessage<'_> {
+    type Response = ChatMeMessage;
+
+    fn path(&self) -> &'static str {
+        "chat.meMessage"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatMeMessage {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel the message was posted to.
+    pub channel: Option<ChannelId>,
+    /// The timestamp of the posted message.
+    pub ts: Option<Ts>,
+}
+
+impl Response for ChatMeMessage {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,35 @@
+mod delete;
+mod delete_scheduled_message;
+mod me_message;
+mod post_ephemeral;
+mod post_message;
+mod schedule_message;
+mod scheduled_messages;
+mod update;
+
+pub use delete::Delete;
+pub use delete_scheduled_message::DeleteScheduledMessage;
+pub use me_message::MeMessage;
+pub use post_ephemeral::PostEphemeral;
+pub use post_message::PostMessage;
+pub use schedule_message::ScheduleMessage;
+pub use scheduled_messages::{ScheduledMessage, ScheduledMessages};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+pub use update::Update;
+
+use crate::request::Request;
+
+/// A marker trait which denotes a request for the `chat` API.
+pub trait ChatQuery: Request {}
+
+/// Metadata attached to a message, which is not visible to users but available to apps.
+///
+/// See: https://api.slack.com/metadata/using
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Metadata {
+    /// The name of the event, e.g. `task_created`.
+    pub event_type: String,
+    /// The payload of the event.
+    pub event_payload: Value,
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    blocks::Block,
+    chat::ChatQuery,
+    id::{ChannelId, UserId},
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.postEphemeral` API, which sends a message visible only to the given user.
+/// One of `text`, `blocks` or `attachments` is required. Start from [`PostEphemeral::new`] and set
+/// the fields you need.
+///
+/// See: https://api.slack.com/methods/chat.postEphemeral
+#[derive(Serialize, Debug, Clone)]
+pub struct PostEphemeral<'a> {
+    /// Channel, private group, or IM channel to send message to.
+    pub channel: &'a ChannelId,
+    /// ID of the user who will receive the ephemeral message. The user should be in the channel.
+    pub user: &'a UserId,
+    /// The formatted text of the message, or the fallback text for notifications when `blocks` is
+    /// given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<&'a str>,
+    /// An array of layout blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// An array of legacy secondary attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Value>>,
+    /// Provide another message's `ts` value to post this message in a thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+}
+
+impl<'a> PostEphemeral<'a> {
+    /// Create a new request to the given user in the given channel, without any content.
+    pub fn new(channel: &'a ChannelId, user: &'a UserId) -> Self {
+        Self {
+            channel,
+            user,
+            text: None,
+            blocks: None,
+            attachments: None,
+            thread_ts: None,
+        }
+    }
+}
+
+impl ChatQuery for PostEphemeral<'_> {}
+
+impl Request for PostEphemeral<'_> {
+    type Response = ChatPostEphemeral;
+
+    fn path(&self) -> &'static str {
+        "chat.postEphemeral"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatPostEphemeral {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The timestamp of the posted message.
+    pub message_ts: Option<Ts>,
+}
+
+impl Response for ChatPostEphemeral {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    blocks::Block,
+    chat::{ChatQuery, Metadata},
+    conversations::Message,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.postMessage` API. One of `text`, `blocks` or `attachments` is required.
+/// Start from [`PostMessage::new`] and set the fields you need.
+///
+/// See: https://api.slack.com/methods/chat.postMessage
+#[derive(Serialize, Debug, Clone)]
+pub struct PostMessage<'a> {
+    /// Channel, private group, or IM channel to send message to.
+    pub channel: &'a ChannelId,
+    /// The formatted text of the message, or the fallback text for notifications when `blocks` is
+    /// given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<&'a str>,
+    /// An array of layout blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// An array of legacy secondary attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Value>>,
+    /// Provide another message's `ts` value to make this message a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+    /// Used in conjunction with `thread_ts` and indicates whether reply should be made visible to
+    /// everyone in the channel or conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    /// Disable Slack markup parsing by setting to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
+    /// JSON object with event_type and event_payload fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Set your bot's user name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<&'a str>,
+    /// Emoji to use as the icon for this message. Overrides `icon_url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_emoji: Option<&'a str>,
+    /// URL to an image to use as the icon for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<&'a str>,
+}
+
+impl<'a> PostMessage<'a> {
+    /// Create a new request to the given channel, without any content.
+    pub fn new(channel: &'a ChannelId) -> Self {
+        Self {
+            channel,
+            text: None,
+            blocks: None,
+            attachments: None,
+            thread_ts: None,
+            reply_broadcast: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            mrkdwn: None,
+            metadata: None,
+            username: None,
+            icon_emoji: None,
+            icon_url: None,
+        }
+    }
+}
+
+impl ChatQuery for PostMessage<'_> {}
+
+impl Request for PostMessage<'_> {
+    type Response = ChatPostMessage;
+
+    fn path(&self) -> &'static str {
+        "chat.postMessage"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatPostMessage {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel the message was posted to.
+    pub channel: Option<ChannelId>,
+    /// The timestamp of the posted message.
+    pub ts: Option<Ts>,
+    /// The posted message.
+    pub message: Option<Message>,
+}
+
+impl Response for ChatPostMessage {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    blocks::Block,
+    chat::{ChatQuery, Metadata},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.scheduleMessage` API. One of `text`, `blocks` or `attachments` is required.
+/// Start from [`ScheduleMessage::new`] and set the fields you need.
+///
+/// See: https://api.slack.com/methods/chat.scheduleMessage
+#[derive(Serialize, Debug, Clone)]
+pub struct ScheduleMessage<'a> {
+    /// Channel, private group, or DM channel to send message to.
+    pub channel: &'a ChannelId,
+    /// Unix timestamp in seconds representing the future time the message should post to Slack.
+    pub post_at: i64,
+    /// The formatted text of the message, or the fallback text for notifications when `blocks` is
+    /// given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<&'a str>,
+    /// An array of layout blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// An array of legacy secondary attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Value>>,
+    /// Provide another message's `ts` value to make this message a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+    /// Used in conjunction with `thread_ts` and indicates whether reply should be made visible to
+    /// everyone in the channel or conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    /// JSON object with event_type and event_payload fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> ScheduleMessage<'a> {
+    /// Create a new request to the given channel at the given time, without any content.
+    pub fn new(channel: &'a ChannelId, post_at: i64) -> Self {
+        Self {
+            channel,
+            post_at,
+            text: None,
+            blocks: None,
+            attachments: None,
+            thread_ts: None,
+            reply_broadcast: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            metadata: None,
+        }
+    }
+}
+
+impl ChatQuery for ScheduleMessage<'_> {}
+
+impl Request for ScheduleMessage<'_> {
+    type Response = ChatScheduleMessage;
+
+    fn path(&self) -> &'static str {
+        "chat.scheduleMessage"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatScheduleMessage {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel the message will be posted to.
+    pub channel: Option<ChannelId>,
+    /// The ID of the scheduled message, which can be used to delete it.
+    pub scheduled_message_id: Option<String>,
+    /// Unix timestamp in seconds the message will be posted at.
+    pub post_at: Option<i64>,
+}
+
+impl Response for ChatScheduleMessage {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chat::ChatQuery,
+    id::{ChannelId, TeamId},
+    request::{Paginated, Request, RequestMethod},
+    response::{Response, ResponseMetadata},
+    ts::Ts,
+};
+
+/// A request for `chat.scheduledMessages.list` API.
+///
+/// See: https://api.slack.com/methods/chat.scheduledMessages.list
+#[derive(Serialize, Debug, Clone)]
+pub struct ScheduledMessages<'a> {
+    /// The channel of the scheduled messages.
+    pub channel: Option<&'a ChannelId>,
+    /// A Unix timestamp of the latest value in the time range.
+    pub latest: Option<Ts>,
+    /// A Unix timestamp of the oldest value in the time range.
+    pub oldest: Option<Ts>,
+    /// Maximum number of original entries to return.
+    pub limit: Option<u64>,
+    /// For pagination purposes, this is the cursor value returned from a previous call to
+    /// `chat.scheduledMessages.list` indicating where you want to start this call from.
+    pub cursor: Option<String>,
+    /// Encoded team id to list channels in, required if org token is used.
+    pub team_id: Option<TeamId>,
+}
+
+impl ChatQuery for ScheduledMessages<'_> {}
+
+impl Request for ScheduledMessages<'_> {
+    type Response = ChatScheduledMessagesList;
+
+    fn path(&self) -> &'static str {
+        "chat.scheduledMessages.list"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+}
+
+impl Paginated for ScheduledMessages<'_> {
+    type Item = ScheduledMessage;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.scheduled_messages.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatScheduledMessagesList {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub scheduled_messages: Option<Vec<ScheduledMessage>>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl Response for ChatScheduledMessagesList {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn next_cursor(&self) -> Option<String> {
+        self.response_metadata.as_ref().and_then(|m| {
+            if m.next_cursor.is_empty() {
+                return None;
+            }
+            Some(m.next_cursor.clone())
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScheduledMessage {
+    /// The ID of the scheduled message.
+    pub id: String,
+    /// The channel the message will be posted to.
+    pub channel_id: ChannelId,
+    /// Unix timestamp in seconds the message will be posted at.
+    pub post_at: i64,
+    /// Unix timestamp in seconds the message was scheduled at.
+    pub date_created: i64,
+    /// The text of the message.
+    pub text: Option<String>,
+}
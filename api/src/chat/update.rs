@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    blocks::Block,
+    chat::{ChatQuery, Metadata},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `chat.update` API. Start from [`Update::new`] and set the fields you need.
+///
+/// See: https://api.slack.com/methods/chat.update
+#[derive(Serialize, Debug, Clone)]
+pub struct Update<'a> {
+    /// Channel containing the message to be updated.
+    pub channel: &'a ChannelId,
+    /// Timestamp of the message to be updated.
+    pub ts: Ts,
+    /// New text for the message, or the fallback text for notifications when `blocks` is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<&'a str>,
+    /// An array of layout blocks. If you don't include this field, the message's previous blocks
+    /// will be retained. To remove previous blocks, include an empty array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// An array of legacy secondary attachments. If you don't include this field, the message's
+    /// previous attachments will be retained. To remove previous attachments, include an empty
+    /// array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Value>>,
+    /// Broadcast an existing thread reply to make it visible to everyone in the channel or
+    /// conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    /// JSON object with event_type and event_payload fields. If you don't include this field, the
+    /// message's previous metadata will be retained.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> Update<'a> {
+    /// Create a new request to update the given message, without any content.
+    pub fn new(channel: &'a ChannelId, ts: Ts) -> Self {
+        Self {
+            channel,
+            ts,
+            text: None,
+            blocks: None,
+            attachments: None,
+            reply_broadcast: None,
+            metadata: None,
+        }
+    }
+}
+
+impl ChatQuery for Update<'_> {}
+
+impl Request for Update<'_> {
+    type Response = ChatUpdate;
+
+    fn path(&self) -> &'static str {
+        "chat.update"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatUpdate {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel of the updated message.
+    pub channel: Option<ChannelId>,
+    /// The timestamp of the updated message.
+    pub ts: Option<Ts>,
+    /// The updated text.
+    pub text: Option<String>,
+}
+
+impl Response for ChatUpdate {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
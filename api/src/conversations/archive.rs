@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.archive` API. Archives a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.archive
+#[derive(Serialize, Debug, Clone)]
+pub struct Archive<'a> {
+    /// ID of conversation to archive.
+    pub channel: &'a ChannelId,
+}
+
+impl ConversationsQuery for Archive<'_> {}
+
+impl Request for Archive<'_> {
+    type Response = ConversationsArchive;
+
+    fn path(&self) -> &'static str {
+        "conversations.archive"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsArchive {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ConversationsArchive {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
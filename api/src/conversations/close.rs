@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.close` API. Closes a direct message or multi-person direct message.
+///
+/// See: https://api.slack.com/methods/conversations.close
+#[derive(Serialize, Debug, Clone)]
+pub struct Close<'a> {
+    /// Conversation to close.
+    pub channel: &'a ChannelId,
+}
+
+impl ConversationsQuery for Close<'_> {}
+
+impl Request for Close<'_> {
+    type Response = ConversationsClose;
+
+    fn path(&self) -> &'static str {
+        "conversations.close"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsClose {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// `true` if nothing was done, e.g. the conversation was already closed.
+    pub no_op: Option<bool>,
+    /// `true` if the conversation was already closed.
+    pub already_closed: Option<bool>,
+}
+
+impl Response for ConversationsClose {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::TeamId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.create` API. Initiates a public or private channel-based
+/// conversation.
+///
+/// See: https://api.slack.com/methods/conversations.create
+#[derive(Serialize, Debug, Clone)]
+pub struct Create<'a> {
+    /// Name of the public or private channel to create.
+    pub name: &'a str,
+    /// Create a private channel instead of a public one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    /// Encoded team ID to create the channel in, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl ConversationsQuery for Create<'_> {}
+
+impl Request for Create<'_> {
+    type Response = ConversationsCreate;
+
+    fn path(&self) -> &'static str {
+        "conversations.create"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsCreate {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The created channel.
+    pub channel: Option<Channel>,
+}
+
+impl Response for ConversationsCreate {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -1,10 +1,11 @@
-use mrkdwn2markdown::Block;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    conversations::ConversationsQuery,
-    request::Request,
+    conversations::{ConversationsQuery, Message},
+    id::ChannelId,
+    request::{Paginated, Request},
     response::{Response, ResponseMetadata},
+    ts::Ts,
 };
 
 /// A request for `conversations.history` API.
@@ -13,11 +14,11 @@ use crate::{
 #[derive(Serialize, Debug, Clone)]
 pub struct History<'a> {
     /// Conversation ID to fetch history for.
-    pub channel: &'a str,
+    pub channel: &'a ChannelId,
     /// Only messages before this Unix timestamp will be included in results.
-    pub latest: Option<f64>,
+    pub latest: Option<Ts>,
     /// Only messages after this Unix timestamp will be included in results.
-    pub oldest: Option<f64>,
+    pub oldest: Option<Ts>,
     /// The maximum number of items to return. Fewer than the requested number of items may be
     /// returned, even if the end of the conversation history hasn't been reached. Maximum of 999.
     pub limit: Option<u64>,
@@ -40,9 +41,23 @@ impl Request for History<'_> {
     }
 }
 
+impl Paginated for History<'_> {
+    type Item = Message;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.messages.as_deref().unwrap_or_default()
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct Conversations {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     pub messages: Option<Vec<Message>>,
     pub response_metadata: Option<ResponseMetadata>,
 }
@@ -52,6 +67,10 @@ impl Response for Conversations {
         self.ok
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn next_cursor(&self) -> Option<String> {
         self.response_metadata.as_ref().and_then(|m| {
             if m.next_cursor.is_empty() {
@@ -61,17 +80,3 @@ impl Response for Conversations {
         })
     }
 }
-
-#[derive(Deserialize, Debug, Clone)]
-pub struct Message {
-    /// User ID of the author.
-    pub user: Option<String>,
-    /// bot ID of the author.
-    pub bot_id: Option<String>,
-    /// The text of the message.
-    pub text: Option<String>,
-    /// The Slack block kit blocks of the message.
-    pub blocks: Option<Vec<Block>>,
-    /// Timestamp of the message.
-    pub ts: String,
-}
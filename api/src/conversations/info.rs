@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{conversations::ConversationsQuery, request::Request, response::Response};
+use crate::{
+    conversations::ConversationsQuery,
+    id::{ChannelId, UserId},
+    request::Request,
+    response::Response,
+    ts::Ts,
+};
 
 /// A request for `conversations.info` API.
 ///
@@ -8,7 +14,22 @@ use crate::{conversations::ConversationsQuery, request::Request, response::Respo
 #[derive(Serialize, Debug, Clone)]
 pub struct Info<'a> {
     /// Conversation ID to learn more about.
-    pub channel: &'a str,
+    pub channel: &'a ChannelId,
+    /// Set to `true` to include the member count for the specified conversation.
+    pub include_num_members: Option<bool>,
+    /// Set this to `true` to receive the locale for this conversation.
+    pub include_locale: Option<bool>,
+}
+
+impl<'a> Info<'a> {
+    /// Create a new request for the given conversation, without any options.
+    pub fn new(channel: &'a ChannelId) -> Self {
+        Self {
+            channel,
+            include_num_members: None,
+            include_locale: None,
+        }
+    }
 }
 
 impl ConversationsQuery for Info<'_> {}
@@ -24,6 +45,8 @@ impl Request for Info<'_> {
 #[derive(Deserialize, Debug, Clone)]
 pub struct ConversationsInfo {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     pub channel: Option<Channel>,
 }
 
@@ -31,11 +54,15 @@ impl Response for ConversationsInfo {
     fn is_ok(&self) -> bool {
         self.ok
     }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Channel {
-    pub id: String,
+    pub id: ChannelId,
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_channel: Option<bool>,
@@ -53,10 +80,16 @@ pub struct Channel {
     pub previous_names: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub num_members: Option<i64>,
+    #[serde(default)]
     pub created: i64,
+    #[serde(default)]
     pub updated: i64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::id::empty_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creator: Option<UserId>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_im: Option<bool>,
@@ -67,9 +100,29 @@ pub struct Channel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<Purpose>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<String>,
+    pub topic: Option<Purpose>,
+    #[serde(
+        default,
+        deserialize_with = "crate::id::empty_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user: Option<UserId>,
+    /// The locale of the conversation, if requested with `include_locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Timestamp of the last message read by the calling user. Only available with a user token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<Ts>,
+    /// The number of unread messages for the calling user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count: Option<u64>,
+    /// The number of unread messages which would be shown in the Slack client, excluding e.g.
+    /// join and leave messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count_display: Option<u64>,
 }
 
+/// The purpose or the topic of a channel.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Purpose {
     pub value: String,
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::{ChannelId, UserId},
+    request::{comma_separated_list, Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.invite` API. Invites users to a channel.
+///
+/// See: https://api.slack.com/methods/conversations.invite
+#[derive(Serialize, Debug, Clone)]
+pub struct Invite<'a> {
+    /// The ID of the public or private channel to invite user(s) to.
+    pub channel: &'a ChannelId,
+    /// The users to invite, up to 1000.
+    #[serde(serialize_with = "comma_separated_list")]
+    pub users: Vec<UserId>,
+    /// When set to `true` and multiple user IDs are provided, continue inviting the valid ones
+    /// while disregarding invalid IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+impl ConversationsQuery for Invite<'_> {}
+
+impl Request for Invite<'_> {
+    type Response = ConversationsInvite;
+
+    fn path(&self) -> &'static str {
+        "conversations.invite"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsInvite {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel the users are invited to.
+    pub channel: Option<Channel>,
+}
+
+impl Response for ConversationsInvite {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.join` API. Joins an existing conversation.
+///
+/// See: https://api.slack.com/methods/conversations.join
+#[derive(Serialize, Debug, Clone)]
+pub struct Join<'a> {
+    /// ID of conversation to join.
+    pub channel: &'a ChannelId,
+}
+
+impl ConversationsQuery for Join<'_> {}
+
+impl Request for Join<'_> {
+    type Response = ConversationsJoin;
+
+    fn path(&self) -> &'static str {
+        "conversations.join"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsJoin {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The joined channel.
+    pub channel: Option<Channel>,
+    /// A warning, e.g. `already_in_channel`.
+    pub warning: Option<String>,
+}
+
+impl Response for ConversationsJoin {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::{ChannelId, UserId},
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.kick` API. Removes a user from a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.kick
+#[derive(Serialize, Debug, Clone)]
+pub struct Kick<'a> {
+    /// ID of conversation to remove user from.
+    pub channel: &'a ChannelId,
+    /// User ID to be removed.
+    pub user: &'a UserId,
+}
+
+impl ConversationsQuery for Kick<'_> {}
+
+impl Request for Kick<'_> {
+    type Response = ConversationsKick;
+
+    fn path(&self) -> &'static str {
+        "conversations.kick"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsKick {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ConversationsKick {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.leave` API. Leaves a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.leave
+#[derive(Serialize, Debug, Clone)]
+pub struct Leave<'a> {
+    /// Conversation to leave.
+    pub channel: &'a ChannelId,
+}
+
+impl ConversationsQuery for Leave<'_> {}
+
+impl Request for Leave<'_> {
+    type Response = ConversationsLeave;
+
+    fn path(&self) -> &'static str {
+        "conversations.leave"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsLeave {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// `true` if the user was not in the channel.
+    pub not_in_channel: Option<bool>,
+}
+
+impl Response for ConversationsLeave {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -4,10 +4,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     conversations::{info::Channel, ConversationsQuery},
-    request::Request,
+    request::{Paginated, Request},
     response::{Response, ResponseMetadata},
 };
 
+/// A list of channel types, which is serialized as a comma-separated string.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChannelTypes {
     inner: Vec<ChannelType>,
@@ -82,7 +83,10 @@ pub struct List {
     pub limit: Option<u64>,
 }
 
-fn from_channel_types<S>(value: &Option<ChannelTypes>, serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn from_channel_types<S>(
+    value: &Option<ChannelTypes>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -108,9 +112,23 @@ impl Request for List {
     }
 }
 
+impl Paginated for List {
+    type Item = Channel;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.channels.as_deref().unwrap_or_default()
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ConversationsList {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     pub channels: Option<Vec<Channel>>,
     pub response_metadata: Option<ResponseMetadata>,
 }
@@ -120,6 +138,10 @@ impl Response for ConversationsList {
         self.ok
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn next_cursor(&self) -> Option<String> {
         self.response_metadata.as_ref().and_then(|m| {
             if m.next_cursor.is_empty() {
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `conversations.mark` API. Sets the read cursor in a channel.
+///
+/// See: https://api.slack.com/methods/conversations.mark
+#[derive(Serialize, Debug, Clone)]
+pub struct Mark<'a> {
+    /// Channel or conversation to set the read cursor for.
+    pub channel: &'a ChannelId,
+    /// Unique identifier of message you want marked as most recently seen in this conversation.
+    pub ts: Ts,
+}
+
+impl ConversationsQuery for Mark<'_> {}
+
+impl Request for Mark<'_> {
+    type Response = ConversationsMark;
+
+    fn path(&self) -> &'static str {
+        "conversations.mark"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsMark {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ConversationsMark {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::{ChannelId, UserId},
+    request::{Paginated, Request},
+    response::{Response, ResponseMetadata},
+};
+
+/// A request for `conversations.members` API, which lists the members of a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.members
+#[derive(Serialize, Debug, Clone)]
+pub struct Members<'a> {
+    /// ID of the conversation to retrieve members for.
+    pub channel: &'a ChannelId,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor
+    /// attribute returned by a previous request's response_metadata. Default value fetches the
+    /// first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be
+    /// returned, even if the end of the members list hasn't been reached.
+    pub limit: Option<u64>,
+}
+
+impl ConversationsQuery for Members<'_> {}
+
+impl Request for Members<'_> {
+    type Response = ConversationsMembers;
+
+    fn path(&self) -> &'static str {
+        "conversations.members"
+    }
+}
+
+impl Paginated for Members<'_> {
+    type Item = UserId;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.members.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsMembers {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// IDs of the members of the conversation.
+    pub members: Option<Vec<UserId>>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl Response for ConversationsMembers {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn next_cursor(&self) -> Option<String> {
+        self.response_metadata.as_ref().and_then(|m| {
+            if m.next_cursor.is_empty() {
+                return None;
+            }
+            Some(m.next_cursor.clone())
+        })
+    }
+}
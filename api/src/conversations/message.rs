@@ -0,0 +1,374 @@
+use std::{collections::HashMap, fmt, fmt::Formatter};
+
+use mrkdwn2markdown::Block;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::{
+    chat::Metadata,
+    files::File,
+    id::{BotId, TeamId, UserId},
+    reactions::Reaction,
+    ts::Ts,
+};
+
+/// A message in a conversation.
+///
+/// See: https://api.slack.com/events/message
+#[derive(Deserialize, Debug, Clone)]
+pub struct Message {
+    /// The subtype of the message, which is `None` for a plain message from a user.
+    pub subtype: Option<Subtype>,
+    /// User ID of the author.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub user: Option<UserId>,
+    /// bot ID of the author.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub bot_id: Option<BotId>,
+    /// The name the message was posted as, e.g. by an incoming webhook or with `chat.postMessage`.
+    pub username: Option<String>,
+    /// The profile of the bot, if the message is from a bot.
+    pub bot_profile: Option<BotProfile>,
+    /// The ID of the app which posted the message.
+    pub app_id: Option<String>,
+    /// The team of the author.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub team: Option<TeamId>,
+    /// The text of the message.
+    pub text: Option<String>,
+    /// The Slack block kit blocks of the message.
+    pub blocks: Option<Vec<Block>>,
+    /// Timestamp of the message.
+    pub ts: Ts,
+    /// Timestamp of the parent message, if the message is in a thread or is the parent of one.
+    pub thread_ts: Option<Ts>,
+    /// The number of replies, if the message is the parent of a thread.
+    pub reply_count: Option<u64>,
+    /// Up to 5 users who replied to the thread.
+    #[serde(default)]
+    pub reply_users: Vec<UserId>,
+    /// Timestamp of the latest reply to the thread.
+    pub latest_reply: Option<Ts>,
+    /// The author of the parent message, if the message is a reply.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub parent_user_id: Option<UserId>,
+    /// Who edited the message and when, if the message has been edited.
+    pub edited: Option<Edited>,
+    /// A unique ID set by the client which sent the message.
+    pub client_msg_id: Option<String>,
+    /// Metadata attached to the message by an app.
+    pub metadata: Option<Metadata>,
+    /// Reactions to the message.
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    /// Files shared in the message.
+    #[serde(default)]
+    pub files: Vec<File>,
+    /// Legacy secondary attachments, including unfurled link previews.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Fields which are not covered above, kept as is for forward compatibility.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl Message {
+    /// Returns `true` if the message is a reply in a thread, not the parent of it.
+    pub fn is_reply(&self) -> bool {
+        self.thread_ts.is_some_and(|ts| ts != self.ts)
+    }
+
+    /// Returns `true` if the message has been edited.
+    pub fn is_edited(&self) -> bool {
+        self.edited.is_some()
+    }
+}
+
+/// The subtype of a message.
+///
+/// See: https://api.slack.com/events/message#subtypes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Subtype {
+    BotMessage,
+    ChannelArchive,
+    ChannelJoin,
+    ChannelLeave,
+    ChannelName,
+    ChannelPurpose,
+    ChannelTopic,
+    ChannelUnarchive,
+    FileShare,
+    GroupJoin,
+    GroupLeave,
+    HuddleThread,
+    MeMessage,
+    MessageChanged,
+    MessageDeleted,
+    MessageReplied,
+    PinnedItem,
+    ReminderAdd,
+    ThreadBroadcast,
+    Tombstone,
+    UnpinnedItem,
+    /// A subtype which is not known to this crate.
+    Other(String),
+}
+
+impl Subtype {
+    /// Returns `true` if the message is a notification of a member joining or leaving.
+    pub fn is_join_or_leave(&self) -> bool {
+        matches!(
+            self,
+            Subtype::ChannelJoin | Subtype::ChannelLeave | Subtype::GroupJoin | Subtype::GroupLeave
+        )
+    }
+}
+
+impl From<&str> for Subtype {
+    fn from(value: &str) -> Self {
+        match value {
+            "bot_message" => Subtype::BotMessage,
+            "channel_archive" => Subtype::ChannelArchive,
+            "channel_join" => Subtype::ChannelJoin,
+            "channel_leave" => Subtype::ChannelLeave,
+            "channel_name" => Subtype::ChannelName,
+            "channel_purpose" => Subtype::ChannelPurpose,
+            "channel_topic" => Subtype::ChannelTopic,
+            "channel_unarchive" => Subtype::ChannelUnarchive,
+            "file_share" => Subtype::FileShare,
+            "group_join" => Subtype::GroupJoin,
+            "group_leave" => Subtype::GroupLeave,
+            "huddle_thread" => Subtype::HuddleThread,
+            "me_message" => Subtype::MeMessage,
+            "message_changed" => Subtype::MessageChanged,
+            "message_deleted" => Subtype::MessageDeleted,
+            "message_replied" => Subtype::MessageReplied,
+            "pinned_item" => Subtype::PinnedItem,
+            "reminder_add" => Subtype::ReminderAdd,
+            "thread_broadcast" => Subtype::ThreadBroadcast,
+            "tombstone" => Subtype::Tombstone,
+            "unpinned_item" => Subtype::UnpinnedItem,
+            _ => Subtype::Other(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for Subtype {
+    fn as_ref(&self) -> &str {
+        match self {
+            Subtype::BotMessage => "bot_message",
+            Subtype::ChannelArchive => "channel_archive",
+            Subtype::ChannelJoin => "channel_join",
+            Subtype::ChannelLeave => "channel_leave",
+            Subtype::ChannelName => "channel_name",
+            Subtype::ChannelPurpose => "channel_purpose",
+            Subtype::ChannelTopic => "channel_topic",
+            Subtype::ChannelUnarchive => "channel_unarchive",
+            Subtype::FileShare => "file_share",
+            Subtype::GroupJoin => "group_join",
+            Subtype::GroupLeave => "group_leave",
+            Subtype::HuddleThread => "huddle_thread",
+            Subtype::MeMessage => "me_message",
+            Subtype::MessageChanged => "message_changed",
+            Subtype::MessageDeleted => "message_deleted",
+            Subtype::MessageReplied => "message_replied",
+            Subtype::PinnedItem => "pinned_item",
+            Subtype::ReminderAdd => "reminder_add",
+            Subtype::ThreadBroadcast => "thread_broadcast",
+            Subtype::Tombstone => "tombstone",
+            Subtype::UnpinnedItem => "unpinned_item",
+            Subtype::Other(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Subtype {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for Subtype {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.as_str().into())
+    }
+}
+
+/// Who edited a message and when.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Edited {
+    /// The user who edited the message.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub user: Option<UserId>,
+    /// When the message was edited.
+    pub ts: Ts,
+}
+
+/// The profile of the bot which posted a message.
+#[derive(Deserialize, Debug, Clone)]
+pub struct BotProfile {
+    /// The ID of the bot.
+    pub id: BotId,
+    /// The name of the bot.
+    pub name: Option<String>,
+    /// The ID of the app the bot belongs to.
+    pub app_id: Option<String>,
+    /// The team of the bot.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub team_id: Option<TeamId>,
+    /// Whether the bot has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// A legacy secondary attachment of a message.
+///
+/// See: https://api.slack.com/reference/messaging/attachments
+#[derive(Deserialize, Debug, Clone)]
+pub struct Attachment {
+    /// A plain-text summary of the attachment.
+    pub fallback: Option<String>,
+    /// The title of the attachment.
+    pub title: Option<String>,
+    /// The URL the title links to.
+    pub title_link: Option<String>,
+    /// The main body text of the attachment.
+    pub text: Option<String>,
+    /// Fields shown in a table inside the attachment.
+    #[serde(default)]
+    pub fields: Vec<AttachmentField>,
+    /// The color of the border along the left side, e.g. `#36a64f`.
+    pub color: Option<String>,
+    /// The name of the author.
+    pub author_name: Option<String>,
+}
+
+/// A field of an attachment.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AttachmentField {
+    pub title: Option<String>,
+    pub value: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn plain_message() {
+        let message: Message = serde_json::from_value(json!({
+            "type": "message",
+            "user": "U123ABC456",
+            "text": "Hello world",
+            "ts": "1355517523.000005",
+            "team": "T123ABC456",
+            "client_msg_id": "6a5e5c7e-6a5e-4c5b-9b4e-7e5e5c7e6a5e",
+            "is_locked": false,
+        }))
+        .unwrap();
+
+        assert_eq!(message.subtype, None);
+        assert_eq!(message.user.as_ref().unwrap().as_str(), "U123ABC456");
+        assert_eq!(message.text.as_deref(), Some("Hello world"));
+        assert!(!message.is_reply());
+        assert!(!message.is_edited());
+        // Fields which are not modeled land in `extra`.
+        assert_eq!(message.extra["type"], "message");
+        assert_eq!(message.extra["is_locked"], false);
+        assert!(!message.extra.contains_key("user"));
+    }
+
+    #[test]
+    fn subtypes() {
+        let subtype = |subtype: &str| -> Subtype {
+            let message: Message = serde_json::from_value(json!({
+                "type": "message",
+                "subtype": subtype,
+                "ts": "1358877455.000010",
+            }))
+            .unwrap();
+            message.subtype.unwrap()
+        };
+
+        assert_eq!(subtype("channel_join"), Subtype::ChannelJoin);
+        assert!(subtype("group_leave").is_join_or_leave());
+        assert_eq!(subtype("thread_broadcast"), Subtype::ThreadBroadcast);
+        // A subtype which is not known falls back to `Other`, and is kept as is.
+        let other = subtype("assistant_app_thread");
+        assert_eq!(other, Subtype::Other("assistant_app_thread".to_string()));
+        assert_eq!(other.to_string(), "assistant_app_thread");
+    }
+
+    #[test]
+    fn edited_reply() {
+        let message: Message = serde_json::from_value(json!({
+            "type": "message",
+            "user": "U123ABC456",
+            "text": "This is an edited reply",
+            "thread_ts": "1482960137.003543",
+            "ts": "1483037603.017503",
+            "parent_user_id": "U222BBB222",
+            "edited": { "user": "U123ABC456", "ts": "1483037650.000000" },
+        }))
+        .unwrap();
+
+        assert!(message.is_reply());
+        assert!(message.is_edited());
+        let edited = message.edited.unwrap();
+        assert_eq!(edited.user.unwrap().as_str(), "U123ABC456");
+        assert_eq!(edited.ts.to_string(), "1483037650.000000");
+        assert_eq!(message.parent_user_id.unwrap().as_str(), "U222BBB222");
+    }
+
+    #[test]
+    fn bot_message_with_attachments() {
+        let message: Message = serde_json::from_value(json!({
+            "type": "message",
+            "subtype": "bot_message",
+            "ts": "1358877455.000010",
+            "text": "Pushing is the answer",
+            "bot_id": "B123ABC456",
+            "username": "github",
+            "icons": {},
+            "bot_profile": {
+                "id": "B123ABC456",
+                "deleted": false,
+                "name": "GitHub",
+                "updated": 1678887800,
+                "app_id": "A123ABC456",
+                "team_id": "T123ABC456",
+            },
+            "attachments": [{
+                "fallback": "Required plain-text summary of the attachment.",
+                "color": "#36a64f",
+                "author_name": "Bobby Tables",
+                "title": "Slack API Documentation",
+                "title_link": "https://api.slack.com/",
+                "text": "Optional text that appears within the attachment",
+                "fields": [{ "title": "Priority", "value": "High", "short": false }],
+                "ts": 123456789,
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(message.subtype, Some(Subtype::BotMessage));
+        assert_eq!(message.bot_id.unwrap().as_str(), "B123ABC456");
+        assert_eq!(message.username.as_deref(), Some("github"));
+        let bot = message.bot_profile.unwrap();
+        assert_eq!(bot.name.as_deref(), Some("GitHub"));
+        assert_eq!(bot.team_id.unwrap().as_str(), "T123ABC456");
+        assert!(!bot.deleted);
+
+        let [attachment] = &message.attachments[..] else { panic!("{:?}", message.attachments) };
+        assert_eq!(attachment.title.as_deref(), Some("Slack API Documentation"));
+        assert_eq!(attachment.title_link.as_deref(), Some("https://api.slack.com/"));
+        assert_eq!(attachment.author_name.as_deref(), Some("Bobby Tables"));
+        assert_eq!(attachment.fields[0].value.as_deref(), Some("High"));
+        assert!(message.extra.contains_key("icons"));
+    }
+}
@@ -1,12 +1,43 @@
+mod archive;
+mod close;
+mod create;
 mod history;
 mod info;
+mod invite;
+mod join;
+mod kick;
+mod leave;
 mod list;
+mod mark;
+mod members;
+mod message;
+mod open;
+mod rename;
 mod replies;
+mod set_purpose;
+mod set_topic;
+mod unarchive;
 
-pub use history::{History, Message};
-pub use info::Info;
-pub use list::{ChannelType, List};
+pub use archive::Archive;
+pub use close::Close;
+pub use create::Create;
+pub use history::History;
+pub use info::{Channel, Info, Purpose};
+pub use invite::Invite;
+pub use join::Join;
+pub use kick::Kick;
+pub use leave::Leave;
+pub(crate) use list::from_channel_types;
+pub use list::{ChannelType, ChannelTypes, List};
+pub use mark::Mark;
+pub use members::Members;
+pub use message::{Attachment, AttachmentField, BotProfile, Edited, Message, Subtype};
+pub use open::Open;
+pub use rename::Rename;
 pub use replies::Replies;
+pub use set_purpose::SetPurpose;
+pub use set_topic::SetTopic;
+pub use unarchive::Unarchive;
 
 use crate::request::Request;
 
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::{ChannelId, UserId},
+    request::{comma_separated, Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.open` API. Opens or resumes a direct message or multi-person direct
+/// message. One of `channel` or `users` is required.
+///
+/// See: https://api.slack.com/methods/conversations.open
+#[derive(Serialize, Debug, Clone)]
+pub struct Open<'a> {
+    /// Resume a conversation by supplying an `im` or `mpim`'s ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<&'a ChannelId>,
+    /// The users to open a conversation with. Supplying one user opens a 1:1 direct message,
+    /// and supplying more opens a multi-person one.
+    #[serde(serialize_with = "comma_separated", skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<UserId>>,
+    /// Return the full conversation, instead of only its ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_im: Option<bool>,
+    /// Do not create a direct message or multi-person direct message. This is used to see if
+    /// there is an existing one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_creation: Option<bool>,
+}
+
+impl ConversationsQuery for Open<'_> {}
+
+impl Request for Open<'_> {
+    type Response = ConversationsOpen;
+
+    fn path(&self) -> &'static str {
+        "conversations.open"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsOpen {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The opened conversation, which has only the ID unless `return_im` is set.
+    pub channel: Option<Channel>,
+    /// `true` if nothing was done, e.g. the conversation was already open.
+    pub no_op: Option<bool>,
+    /// `true` if the conversation was already open.
+    pub already_open: Option<bool>,
+}
+
+impl Response for ConversationsOpen {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
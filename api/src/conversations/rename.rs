@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.rename` API. Renames a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.rename
+#[derive(Serialize, Debug, Clone)]
+pub struct Rename<'a> {
+    /// ID of conversation to rename.
+    pub channel: &'a ChannelId,
+    /// New name for conversation.
+    pub name: &'a str,
+}
+
+impl ConversationsQuery for Rename<'_> {}
+
+impl Request for Rename<'_> {
+    type Response = ConversationsRename;
+
+    fn path(&self) -> &'static str {
+        "conversations.rename"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsRename {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The renamed channel.
+    pub channel: Option<Channel>,
+}
+
+impl Response for ConversationsRename {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
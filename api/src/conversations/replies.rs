@@ -1,8 +1,10 @@
 use serde::Serialize;
 
 use crate::{
-    conversations::{history::Conversations, ConversationsQuery},
-    request::Request,
+    conversations::{history::Conversations, ConversationsQuery, Message},
+    id::ChannelId,
+    request::{Paginated, Request},
+    ts::Ts,
 };
 
 /// A request for `conversations.replies` API.
@@ -11,22 +13,26 @@ use crate::{
 #[derive(Serialize, Debug, Clone)]
 pub struct Replies<'a> {
     /// Conversation ID to fetch thread from.
-    pub channel: &'a str,
+    pub channel: &'a ChannelId,
     /// Unique identifier of either a thread’s parent message or a message in the thread. `ts` must
     /// be the timestamp of an existing message with 0 or more replies. If there are no replies
     /// then just the single message referenced by `ts` will return - it is just an ordinary,
     /// unthreaded message.
-    pub ts: f64,
+    pub ts: Ts,
     /// Only messages before this Unix timestamp will be included in results.
-    pub latest: Option<f64>,
+    pub latest: Option<Ts>,
     /// Only messages after this Unix timestamp will be included in results.
-    pub oldest: Option<f64>,
+    pub oldest: Option<Ts>,
     /// The maximum number of items to return. Fewer than the requested number of items may be
     /// returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u64>,
     /// Include messages with `oldest` or `latest` timestamps in results. Ignored unless either
     /// timestamp is specified.
     pub inclusive: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor
+    /// attribute returned by a previous request's response_metadata. Default value fetches the
+    /// first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<String>,
 }
 
 impl ConversationsQuery for Replies<'_> {}
@@ -38,3 +44,15 @@ impl Request for Replies<'_> {
         "conversations.replies"
     }
 }
+
+impl Paginated for Replies<'_> {
+    type Item = Message;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.messages.as_deref().unwrap_or_default()
+    }
+}
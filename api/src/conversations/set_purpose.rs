@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.setPurpose` API. Sets the purpose for a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.setPurpose
+#[derive(Serialize, Debug, Clone)]
+pub struct SetPurpose<'a> {
+    /// Channel to set the purpose of.
+    pub channel: &'a ChannelId,
+    /// The new purpose.
+    pub purpose: &'a str,
+}
+
+impl ConversationsQuery for SetPurpose<'_> {}
+
+impl Request for SetPurpose<'_> {
+    type Response = ConversationsSetPurpose;
+
+    fn path(&self) -> &'static str {
+        "conversations.setPurpose"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsSetPurpose {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel with the new purpose.
+    pub channel: Option<Channel>,
+}
+
+impl Response for ConversationsSetPurpose {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{Channel, ConversationsQuery},
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.setTopic` API. Sets the topic for a conversation.
+///
+/// See: https://api.slack.com/methods/conversations.setTopic
+#[derive(Serialize, Debug, Clone)]
+pub struct SetTopic<'a> {
+    /// Conversation to set the topic of.
+    pub channel: &'a ChannelId,
+    /// The new topic string. Does not support formatting or linkification.
+    pub topic: &'a str,
+}
+
+impl ConversationsQuery for SetTopic<'_> {}
+
+impl Request for SetTopic<'_> {
+    type Response = ConversationsSetTopic;
+
+    fn path(&self) -> &'static str {
+        "conversations.setTopic"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsSetTopic {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The channel with the new topic.
+    pub channel: Option<Channel>,
+}
+
+impl Response for ConversationsSetTopic {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
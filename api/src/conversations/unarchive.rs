@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::ConversationsQuery,
+    id::ChannelId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `conversations.unarchive` API. Reverses conversation archival.
+///
+/// See: https://api.slack.com/methods/conversations.unarchive
+#[derive(Serialize, Debug, Clone)]
+pub struct Unarchive<'a> {
+    /// ID of conversation to unarchive.
+    pub channel: &'a ChannelId,
+}
+
+impl ConversationsQuery for Unarchive<'_> {}
+
+impl Request for Unarchive<'_> {
+    type Response = ConversationsUnarchive;
+
+    fn path(&self) -> &'static str {
+        "conversations.unarchive"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConversationsUnarchive {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ConversationsUnarchive {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
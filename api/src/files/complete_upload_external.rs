@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::{File, FilesQuery},
+    id::{ChannelId, FileId},
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `files.completeUploadExternal` API, which finishes uploading files and optionally
+/// shares them to a channel.
+///
+/// See: https://api.slack.com/methods/files.completeUploadExternal
+#[derive(Serialize, Debug, Clone)]
+pub struct CompleteUploadExternal<'a> {
+    /// The files to complete, returned by `files.getUploadURLExternal`.
+    pub files: Vec<FileSummary>,
+    /// Channel ID where the files will be shared. If not specified the files will be private.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<&'a ChannelId>,
+    /// Provide another message's `ts` value to upload the files as a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+    /// The message text introducing the files in the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_comment: Option<&'a str>,
+}
+
+/// A file to complete the upload of.
+#[derive(Serialize, Debug, Clone)]
+pub struct FileSummary {
+    /// The ID of the file.
+    pub id: FileId,
+    /// The title of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl FilesQuery for CompleteUploadExternal<'_> {}
+
+impl Request for CompleteUploadExternal<'_> {
+    type Response = FilesCompleteUploadExternal;
+
+    fn path(&self) -> &'static str {
+        "files.completeUploadExternal"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FilesCompleteUploadExternal {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The uploaded files.
+    pub files: Option<Vec<File>>,
+}
+
+impl Response for FilesCompleteUploadExternal {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
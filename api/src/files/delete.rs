@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::FilesQuery,
+    id::FileId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+};
+
+/// A request for `files.delete` API.
+///
+/// See: https://api.slack.com/methods/files.delete
+#[derive(Serialize, Debug, Clone)]
+pub struct Delete<'a> {
+    /// ID of file to delete.
+    pub file: &'a FileId,
+}
+
+impl FilesQuery for Delete<'_> {}
+
+impl Request for Delete<'_> {
+    type Response = FilesDelete;
+
+    fn path(&self) -> &'static str {
+        "files.delete"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FilesDelete {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for FilesDelete {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
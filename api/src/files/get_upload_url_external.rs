@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::FilesQuery,
+    id::FileId,
+    request::{Request, RequestMethod},
+    response::Response,
+};
+
+/// A request for `files.getUploadURLExternal` API, which is the first step of uploading a file.
+/// Upload the content to the returned `upload_url`, then call `files.completeUploadExternal`.
+///
+/// See: https://api.slack.com/methods/files.getUploadURLExternal
+#[derive(Serialize, Debug, Clone)]
+pub struct GetUploadUrlExternal<'a> {
+    /// Name of the file being uploaded.
+    pub filename: &'a str,
+    /// Size in bytes of the file being uploaded.
+    pub length: u64,
+    /// Description of image for screen-reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_txt: Option<&'a str>,
+    /// Syntax type of the snippet being uploaded, e.g. `python`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet_type: Option<&'a str>,
+}
+
+impl FilesQuery for GetUploadUrlExternal<'_> {}
+
+impl Request for GetUploadUrlExternal<'_> {
+    type Response = FilesGetUploadUrlExternal;
+
+    fn path(&self) -> &'static str {
+        "files.getUploadURLExternal"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FilesGetUploadUrlExternal {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The URL to upload the content of the file to.
+    pub upload_url: Option<String>,
+    /// The ID of the file, to be passed to `files.completeUploadExternal`.
+    pub file_id: Option<FileId>,
+}
+
+impl Response for FilesGetUploadUrlExternal {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::FilesQuery,
+    id::{FileId, UserId},
+    request::Request,
+    response::Response,
+};
+
+/// A request for `files.info` API.
+///
+/// See: https://api.slack.com/methods/files.info
+#[derive(Serialize, Debug, Clone)]
+pub struct Info<'a> {
+    /// Specify a file by providing its ID.
+    pub file: &'a FileId,
+}
+
+impl FilesQuery for Info<'_> {}
+
+impl Request for Info<'_> {
+    type Response = FilesInfo;
+
+    fn path(&self) -> &'static str {
+        "files.info"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FilesInfo {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub file: Option<File>,
+}
+
+impl Response for FilesInfo {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// A file object.
+///
+/// See: https://api.slack.com/types/file
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct File {
+    /// The ID of the file.
+    pub id: FileId,
+    /// The name of the file.
+    pub name: Option<String>,
+    /// The title of the file.
+    pub title: Option<String>,
+    /// The MIME type of the file, e.g. `image/png`.
+    pub mimetype: Option<String>,
+    /// The type of the file, e.g. `png`.
+    pub filetype: Option<String>,
+    /// The size of the file in bytes.
+    pub size: Option<u64>,
+    /// The user who uploaded the file.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub user: Option<UserId>,
+    /// When the file was created, in Unix time.
+    pub created: Option<i64>,
+    /// The URL of the file content, which requires the token in the `Authorization` header.
+    pub url_private: Option<String>,
+    /// The URL to download the file content, which requires the token as well.
+    pub url_private_download: Option<String>,
+    /// The URL to the page of the file in Slack.
+    pub permalink: Option<String>,
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::{File, FilesQuery},
+    id::{ChannelId, TeamId, UserId},
+    request::Request,
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `files.list` API. Unlike most of the APIs, this is paginated with `page` and
+/// `count`, not with a cursor.
+///
+/// See: https://api.slack.com/methods/files.list
+#[derive(Serialize, Debug, Clone)]
+pub struct List<'a> {
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub channel: Option<&'a ChannelId>,
+    /// Filter files created by a single user.
+    pub user: Option<&'a UserId>,
+    /// Filter files created after this timestamp (inclusive).
+    pub ts_from: Option<Ts>,
+    /// Filter files created before this timestamp (inclusive).
+    pub ts_to: Option<Ts>,
+    /// Filter files by type, e.g. `images,pdfs`.
+    pub types: Option<&'a str>,
+    /// The number of items to return per page.
+    pub count: Option<u64>,
+    /// The page number of results to return.
+    pub page: Option<u64>,
+    /// Encoded team id to list files in, required if org token is used.
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl FilesQuery for List<'_> {}
+
+impl Request for List<'_> {
+    type Response = FilesList;
+
+    fn path(&self) -> &'static str {
+        "files.list"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FilesList {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub files: Option<Vec<File>>,
+    pub paging: Option<Paging>,
+}
+
+impl Response for FilesList {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// The page-based pagination information.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Paging {
+    /// The number of items per page.
+    pub count: u64,
+    /// The total number of items.
+    pub total: u64,
+    /// The current page number.
+    pub page: u64,
+    /// The total number of pages.
+    pub pages: u64,
+}
@@ -0,0 +1,16 @@
+mod complete_upload_external;
+mod delete;
+mod get_upload_url_external;
+mod info;
+mod list;
+
+pub use complete_upload_external::{CompleteUploadExternal, FileSummary};
+pub use delete::Delete;
+pub use get_upload_url_external::GetUploadUrlExternal;
+pub use info::{File, Info};
+pub use list::{List, Paging};
+
+use crate::request::Request;
+
+/// A marker trait which denotes a request for the `files` API.
+pub trait FilesQuery: Request {}
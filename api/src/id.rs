@@ -0,0 +1,194 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Define a newtype for a Slack ID, which starts with one of the given prefixes followed by
+/// uppercase alphanumerics, and (de)serializes as a plain string.
+macro_rules! id {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, [$($prefix:literal),+]) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            /// The prefixes which the ID can start with.
+            pub const PREFIXES: &'static [char] = &[$($prefix),+];
+
+            /// Returns the ID as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.starts_with(Self::PREFIXES)
+                    && s.len() > 1
+                    && s.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+                {
+                    Ok(Self(s.to_string()))
+                } else {
+                    Err(ParseIdError { kind: $kind, value: s.to_string() })
+                }
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ParseIdError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+id!(
+    /// A conversation ID, e.g. `C1234567890`. Private channels may start with `G`, and direct
+    /// messages with `D`.
+    ChannelId,
+    "channel ID",
+    ['C', 'G', 'D']
+);
+
+id!(
+    /// A user ID, e.g. `U1234567890`. Users of Enterprise Grid may start with `W`.
+    UserId,
+    "user ID",
+    ['U', 'W']
+);
+
+id!(
+    /// A bot ID, e.g. `B1234567890`.
+    BotId,
+    "bot ID",
+    ['B']
+);
+
+id!(
+    /// A team (workspace) ID, e.g. `T1234567890`. Enterprise Grid organizations start with `E`.
+    TeamId,
+    "team ID",
+    ['T', 'E']
+);
+
+id!(
+    /// A usergroup ID, e.g. `S1234567890`.
+    UsergroupId,
+    "usergroup ID",
+    ['S']
+);
+
+id!(
+    /// A file ID, e.g. `F1234567890`.
+    FileId,
+    "file ID",
+    ['F']
+);
+
+/// Deserialize an optional ID, treating an empty string as `None`. Slack sends e.g.
+/// `"creator": ""` instead of omitting the field, which would otherwise fail the whole response.
+/// Use it with `#[serde(default, deserialize_with = "crate::id::empty_as_none")]`.
+pub fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = ParseIdError>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => s.parse().map(Some).map_err(de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+/// An error which can be returned when parsing a Slack ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError {
+    kind: &'static str,
+    value: String,
+}
+
+impl Display for ParseIdError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid {}: {}", self.kind, self.value)
+    }
+}
+
+impl Error for ParseIdError {}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    struct Item {
+        #[serde(default, deserialize_with = "empty_as_none")]
+        user: Option<UserId>,
+    }
+
+    fn user(json: &str) -> Result<Option<UserId>, serde_json::Error> {
+        serde_json::from_str::<Item>(json).map(|item| item.user)
+    }
+
+    #[test]
+    fn parses_ids() {
+        assert_eq!("C0123ABCD".parse::<ChannelId>().unwrap().as_str(), "C0123ABCD");
+        assert!("G0123ABCD".parse::<ChannelId>().is_ok());
+        assert!("W0123ABCD".parse::<UserId>().is_ok());
+        for s in ["", "U", "C0123ABCD", "u0123abcd", "U0123-ABCD"] {
+            assert!(s.parse::<UserId>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn deserializes_empty_string_as_none() {
+        assert_eq!(user(r#"{"user":"U0123ABCD"}"#).unwrap(), Some("U0123ABCD".parse().unwrap()));
+        assert_eq!(user(r#"{"user":""}"#).unwrap(), None);
+        assert_eq!(user(r#"{"user":null}"#).unwrap(), None);
+        assert_eq!(user("{}").unwrap(), None);
+        assert!(user(r#"{"user":"C0123ABCD"}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_string_as_required_id() {
+        assert!(serde_json::from_str::<UserId>(r#""""#).is_err());
+    }
+}
@@ -1,6 +1,12 @@
+pub mod blocks;
 pub mod bots;
+pub mod chat;
 pub mod conversations;
+pub mod files;
+pub mod id;
+pub mod reactions;
 pub mod request;
 pub mod response;
+pub mod ts;
 pub mod usergroups;
 pub mod users;
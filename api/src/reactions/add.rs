@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::ChannelId,
+    reactions::ReactionsQuery,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `reactions.add` API.
+///
+/// See: https://api.slack.com/methods/reactions.add
+#[derive(Serialize, Debug, Clone)]
+pub struct Add<'a> {
+    /// Channel where the message to add reaction to was posted.
+    pub channel: &'a ChannelId,
+    /// Reaction (emoji) name, without colons.
+    pub name: &'a str,
+    /// Timestamp of the message to add reaction to.
+    pub timestamp: Ts,
+}
+
+impl ReactionsQuery for Add<'_> {}
+
+impl Request for Add<'_> {
+    type Response = ReactionsAdd;
+
+    fn path(&self) -> &'static str {
+        "reactions.add"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsAdd {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ReactionsAdd {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::Message,
+    id::{ChannelId, FileId},
+    reactions::ReactionsQuery,
+    request::Request,
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `reactions.get` API, which returns the reactions of an item. One of `file`, or the
+/// combination of `channel` and `timestamp` is required.
+///
+/// See: https://api.slack.com/methods/reactions.get
+#[derive(Serialize, Debug, Clone)]
+pub struct Get<'a> {
+    /// Channel where the message to get reactions for was posted.
+    pub channel: Option<&'a ChannelId>,
+    /// Timestamp of the message to get reactions for.
+    pub timestamp: Option<Ts>,
+    /// File to get reactions for.
+    pub file: Option<&'a FileId>,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+}
+
+impl ReactionsQuery for Get<'_> {}
+
+impl Request for Get<'_> {
+    type Response = ReactionsGet;
+
+    fn path(&self) -> &'static str {
+        "reactions.get"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsGet {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The type of the item, e.g. `message`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// The channel of the message.
+    pub channel: Option<ChannelId>,
+    /// The message, with its `reactions`.
+    pub message: Option<Message>,
+}
+
+impl Response for ReactionsGet {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
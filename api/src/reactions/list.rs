@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::Message,
+    id::{ChannelId, TeamId, UserId},
+    reactions::ReactionsQuery,
+    request::{Paginated, Request},
+    response::{Response, ResponseMetadata},
+};
+
+/// A request for `reactions.list` API, which lists items reacted to by a user.
+///
+/// See: https://api.slack.com/methods/reactions.list
+#[derive(Serialize, Debug, Clone)]
+pub struct List<'a> {
+    /// Show reactions made by this user. Defaults to the authed user.
+    pub user: Option<&'a UserId>,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor
+    /// attribute returned by a previous request's response_metadata. Default value fetches the
+    /// first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be
+    /// returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u64>,
+    /// Encoded team id to list reactions in, required if org token is used.
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl ReactionsQuery for List<'_> {}
+
+impl Request for List<'_> {
+    type Response = ReactionsList;
+
+    fn path(&self) -> &'static str {
+        "reactions.list"
+    }
+}
+
+impl Paginated for List<'_> {
+    type Item = ReactedItem;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.items.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsList {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub items: Option<Vec<ReactedItem>>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl Response for ReactionsList {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn next_cursor(&self) -> Option<String> {
+        self.response_metadata.as_ref().and_then(|m| {
+            if m.next_cursor.is_empty() {
+                return None;
+            }
+            Some(m.next_cursor.clone())
+        })
+    }
+}
+
+/// An item reacted to by the user.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactedItem {
+    /// The type of the item, e.g. `message`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The channel of the message.
+    pub channel: Option<ChannelId>,
+    /// The message, with its `reactions`.
+    pub message: Option<Message>,
+}
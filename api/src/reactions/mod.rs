@@ -0,0 +1,28 @@
+mod add;
+mod get;
+mod list;
+mod remove;
+
+pub use add::Add;
+pub use get::Get;
+pub use list::{List, ReactedItem};
+pub use remove::Remove;
+use serde::{Deserialize, Serialize};
+
+use crate::{id::UserId, request::Request};
+
+/// A marker trait which denotes a request for the `reactions` API.
+pub trait ReactionsQuery: Request {}
+
+/// An emoji reaction to a message or a file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Reaction {
+    /// The name of the emoji, without colons, e.g. `thumbsup`. Skin tones are suffixed, e.g.
+    /// `thumbsup::skin-tone-2`.
+    pub name: String,
+    /// The number of users who reacted.
+    pub count: u64,
+    /// The users who reacted. This may be fewer than `count` unless `full` is requested.
+    #[serde(default)]
+    pub users: Vec<UserId>,
+}
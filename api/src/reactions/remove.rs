@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{ChannelId, FileId},
+    reactions::ReactionsQuery,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    ts::Ts,
+};
+
+/// A request for `reactions.remove` API. One of `file`, or the combination of `channel` and
+/// `timestamp` is required.
+///
+/// See: https://api.slack.com/methods/reactions.remove
+#[derive(Serialize, Debug, Clone)]
+pub struct Remove<'a> {
+    /// Reaction (emoji) name, without colons.
+    pub name: &'a str,
+    /// Channel where the message to remove reaction from was posted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<&'a ChannelId>,
+    /// Timestamp of the message to remove reaction from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Ts>,
+    /// File to remove reaction from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<&'a FileId>,
+}
+
+impl ReactionsQuery for Remove<'_> {}
+
+impl Request for Remove<'_> {
+    type Response = ReactionsRemove;
+
+    fn path(&self) -> &'static str {
+        "reactions.remove"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsRemove {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for ReactionsRemove {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 use crate::response::Response;
 
@@ -20,6 +20,31 @@ pub trait Request: Serialize + Debug + Clone {
     fn method(&self) -> RequestMethod {
         RequestMethod::Get
     }
+
+    /// Returns how the request parameters are encoded. Defaults to the query string for `GET`, and
+    /// the `application/x-www-form-urlencoded` body for `POST`. Methods which take structured
+    /// arguments, such as Block Kit blocks, should use JSON.
+    fn encoding(&self) -> RequestEncoding {
+        match self.method() {
+            RequestMethod::Get => RequestEncoding::Query,
+            RequestMethod::Post => RequestEncoding::Form,
+        }
+    }
+}
+
+/// A trait for a request which supports cursor-based pagination, which defines how to set the
+/// cursor to the request, and how to take the items out of a page.
+///
+/// See: https://api.slack.com/apis/pagination
+pub trait Paginated: Request {
+    /// The type of the items in a page.
+    type Item: Debug + Clone;
+
+    /// Set the cursor to fetch the page from.
+    fn set_cursor(&mut self, cursor: Option<String>);
+
+    /// Returns the items in the page.
+    fn items(response: &Self::Response) -> &[Self::Item];
 }
 
 /// An enum representing the HTTP request method.
@@ -51,3 +76,46 @@ impl Debug for RequestMethod {
         write!(f, "{}", self)
     }
 }
+
+/// An enum representing how the request parameters are encoded.
+///
+/// See: https://api.slack.com/web#posting_json
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestEncoding {
+    /// As the query string of the URL.
+    Query,
+    /// As the `application/x-www-form-urlencoded` body.
+    Form,
+    /// As the `application/json` body.
+    Json,
+}
+
+/// Serialize a list of values, e.g. IDs, as a comma-separated string, which is the form Slack
+/// expects for the list arguments.
+pub(crate) fn comma_separated<S, T>(
+    value: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<str>,
+{
+    match value {
+        Some(v) => comma_separated_list(v, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a required list of values as a comma-separated string. See [`comma_separated`].
+pub(crate) fn comma_separated_list<S, T>(value: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<str>,
+{
+    value
+        .iter()
+        .map(|t| t.as_ref())
+        .collect::<Vec<&str>>()
+        .join(",")
+        .serialize(serializer)
+}
@@ -11,6 +11,11 @@ pub trait Response: DeserializeOwned + Debug + Clone {
         false
     }
 
+    /// Returns the `error` code of the response, if any, e.g. `channel_not_found`.
+    fn error(&self) -> Option<&str> {
+        None
+    }
+
     /// Returns the next cursor for pagination, if any.
     fn next_cursor(&self) -> Option<String> {
         None
@@ -19,5 +24,37 @@ pub trait Response: DeserializeOwned + Debug + Clone {
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ResponseMetadata {
+    /// The cursor for the next page. Empty if there are no more pages.
+    #[serde(default)]
     pub next_cursor: String,
+    /// Warnings and errors in detail, which may accompany a failed request e.g.
+    /// `invalid_arguments`.
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
+
+/// A failed response from the Slack API. Any response can be deserialized into this to get the
+/// details of the error, regardless of the method.
+///
+/// See: https://api.slack.com/web#evaluating_responses
+#[derive(Deserialize, Debug, Clone)]
+pub struct ErrorResponse {
+    pub ok: bool,
+    /// The error code.
+    pub error: Option<String>,
+    /// The scopes required by the method, along with `missing_scope` error.
+    pub needed: Option<String>,
+    /// The scopes the token has, along with `missing_scope` error.
+    pub provided: Option<String>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl Response for ErrorResponse {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
@@ -0,0 +1,228 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use jiff::Timestamp;
+use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A Slack message timestamp, e.g. `1234567890.123456`, which is also the identifier of a message
+/// in a conversation. It is kept as the integer seconds and microseconds so that it round-trips
+/// exactly, which is not the case with `f64`.
+///
+/// See: https://api.slack.com/messaging/retrieving#individual_messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ts {
+    seconds: i64,
+    microseconds: u32,
+}
+
+impl Ts {
+    /// Create a new timestamp from the seconds and microseconds since the Unix epoch. Returns
+    /// `None` if the microseconds are out of range.
+    pub fn new(seconds: i64, microseconds: u32) -> Option<Self> {
+        (microseconds < 1_000_000).then_some(Self { seconds, microseconds })
+    }
+
+    /// Returns the seconds since the Unix epoch.
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Returns the microseconds part of the timestamp.
+    pub fn microseconds(&self) -> u32 {
+        self.microseconds
+    }
+
+    /// Parse the timestamp in the permalink form, e.g. `p1234567890123456`. The leading `p` is
+    /// optional.
+    pub fn from_permalink(s: &str) -> Result<Self, ParseTsError> {
+        let digits = s.strip_prefix('p').unwrap_or(s);
+        if digits.len() <= 6 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTsError(s.to_string()));
+        }
+        let (seconds, microseconds) = digits.split_at(digits.len() - 6);
+        Ok(Self {
+            seconds: seconds.parse().map_err(|_| ParseTsError(s.to_string()))?,
+            microseconds: microseconds.parse().map_err(|_| ParseTsError(s.to_string()))?,
+        })
+    }
+
+    /// Returns the timestamp in the permalink form, e.g. `p1234567890123456`.
+    pub fn to_permalink(&self) -> String {
+        format!("p{}{:06}", self.seconds, self.microseconds)
+    }
+
+    /// Convert to `jiff::Timestamp`.
+    pub fn to_timestamp(&self) -> Result<Timestamp, jiff::Error> {
+        Timestamp::new(self.seconds, self.microseconds as i32 * 1000)
+    }
+}
+
+impl From<Timestamp> for Ts {
+    /// Convert from `jiff::Timestamp`, truncating to microseconds.
+    fn from(value: Timestamp) -> Self {
+        let microseconds = value.as_microsecond();
+        Self {
+            seconds: microseconds.div_euclid(1_000_000),
+            microseconds: microseconds.rem_euclid(1_000_000) as u32,
+        }
+    }
+}
+
+impl FromStr for Ts {
+    type Err = ParseTsError;
+
+    /// Parse the timestamp in the form of `1234567890.123456`. The fractional part can be omitted
+    /// or shorter than 6 digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (seconds, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if seconds.is_empty()
+            || fraction.len() > 6
+            || !seconds.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseTsError(s.to_string()));
+        }
+        Ok(Self {
+            seconds: seconds.parse().map_err(|_| ParseTsError(s.to_string()))?,
+            microseconds: format!("{fraction:0<6}")
+                .parse()
+                .map_err(|_| ParseTsError(s.to_string()))?,
+        })
+    }
+}
+
+impl Display for Ts {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}.{:06}", self.seconds, self.microseconds)
+    }
+}
+
+impl Serialize for Ts {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ts {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TsVisitor;
+
+        impl Visitor<'_> for TsVisitor {
+            type Value = Ts;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a Slack timestamp string e.g. \"1234567890.123456\"")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TsVisitor)
+    }
+}
+
+/// An error which can be returned when parsing a Slack timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTsError(String);
+
+impl Display for ParseTsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid Slack timestamp: {}", self.0)
+    }
+}
+
+impl Error for ParseTsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_microseconds() {
+        let ts = Ts::new(1234567890, 42).unwrap();
+        assert_eq!(ts.to_string(), "1234567890.000042");
+        assert_eq!(ts.to_permalink(), "p1234567890000042");
+        assert_eq!("1234567890.000042".parse(), Ok(ts));
+        assert_eq!("1234567890.12".parse(), Ok(Ts::new(1234567890, 120000).unwrap()));
+        assert_eq!("1234567890".parse(), Ok(Ts::new(1234567890, 0).unwrap()));
+    }
+
+    #[test]
+    fn rejects_invalid_strings() {
+        for s in ["", ".123456", "1234567890.1234567", "1234567890.12a", "-1.0", "1 .0"] {
+            assert!(s.parse::<Ts>().is_err(), "{s}");
+        }
+        assert_eq!(Ts::new(0, 1_000_000), None);
+    }
+
+    #[test]
+    fn parses_permalink() {
+        let ts = Ts::new(1234567890, 123456).unwrap();
+        assert_eq!(Ts::from_permalink("p1234567890123456"), Ok(ts));
+        assert_eq!(Ts::from_permalink("1234567890123456"), Ok(ts));
+        assert_eq!(Ts::from_permalink("p1000000"), Ok(Ts::new(1, 0).unwrap()));
+    }
+
+    #[test]
+    fn rejects_short_or_overlong_permalink() {
+        // Only the microseconds, or fewer digits.
+        for s in ["p", "p123456", "p12345"] {
+            assert!(Ts::from_permalink(s).is_err(), "{s}");
+        }
+        // The seconds overflow `i64`.
+        assert!(Ts::from_permalink("p99999999999999999999123456").is_err());
+        // Not digits.
+        assert!(Ts::from_permalink("p1234567890.123456").is_err());
+        assert!(Ts::from_permalink("q1234567890123456").is_err());
+    }
+
+    #[test]
+    fn permalink_round_trip() {
+        for permalink in ["p1234567890123456", "p1234567890000001", "p0000000"] {
+            let ts = Ts::from_permalink(permalink).unwrap();
+            assert_eq!(Ts::from_permalink(&ts.to_permalink()), Ok(ts));
+        }
+        assert_eq!(
+            Ts::from_permalink("p1234567890000001").unwrap().to_permalink(),
+            "p1234567890000001"
+        );
+        assert_eq!(Ts::from_permalink("p0000000").unwrap().to_permalink(), "p0000000");
+    }
+
+    #[test]
+    fn timestamp_round_trip() {
+        let ts = Ts::new(1234567890, 123456).unwrap();
+        assert_eq!(Ts::from(ts.to_timestamp().unwrap()), ts);
+        // Before the epoch, the microseconds are still non-negative.
+        let ts = Ts::from(Timestamp::new(-1, 500_000_000).unwrap());
+        assert_eq!((ts.seconds(), ts.microseconds()), (-1, 500000));
+    }
+
+    #[test]
+    fn serde() {
+        let ts: Ts = serde_json::from_str(r#""1234567890.000042""#).unwrap();
+        assert_eq!(ts, Ts::new(1234567890, 42).unwrap());
+        assert_eq!(serde_json::to_string(&ts).unwrap(), r#""1234567890.000042""#);
+    }
+
+    #[test]
+    fn rejects_non_string_json() {
+        for json in ["1234567890.123456", "1234567890", "null", "true", "[]", "{}"] {
+            assert!(serde_json::from_str::<Ts>(json).is_err(), "{json}");
+        }
+    }
+}
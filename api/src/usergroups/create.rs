@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{ChannelId, TeamId},
+    request::{comma_separated, Request, RequestEncoding, RequestMethod},
+    response::Response,
+    usergroups::{Usergroup, UsergroupsQuery},
+};
+
+/// A request for `usergroups.create` API. Start from [`Create::new`] and set the fields you need.
+///
+/// See: https://api.slack.com/methods/usergroups.create
+#[derive(Serialize, Debug, Clone)]
+pub struct Create<'a> {
+    /// A name for the usergroup. Must be unique among usergroups.
+    pub name: &'a str,
+    /// A mention handle. Must be unique among channels, users and usergroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<&'a str>,
+    /// A short description of the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    /// Channels used as a default for the usergroup.
+    #[serde(serialize_with = "comma_separated", skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<ChannelId>>,
+    /// Include the number of users in the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /// Encoded team id where the usergroup is, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl<'a> Create<'a> {
+    /// Create a new request to create a usergroup with the given name.
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            handle: None,
+            description: None,
+            channels: None,
+            include_count: None,
+            team_id: None,
+        }
+    }
+}
+
+impl UsergroupsQuery for Create<'_> {}
+
+impl Request for Create<'_> {
+    type Response = UsergroupsCreate;
+
+    fn path(&self) -> &'static str {
+        "usergroups.create"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsCreate {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The created usergroup.
+    pub usergroup: Option<Usergroup>,
+}
+
+impl Response for UsergroupsCreate {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
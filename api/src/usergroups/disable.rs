@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{TeamId, UsergroupId},
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    usergroups::{Usergroup, UsergroupsQuery},
+};
+
+/// A request for `usergroups.disable` API. Disables an existing usergroup.
+///
+/// See: https://api.slack.com/methods/usergroups.disable
+#[derive(Serialize, Debug, Clone)]
+pub struct Disable<'a> {
+    /// The usergroup to disable.
+    pub usergroup: &'a UsergroupId,
+    /// Include the number of users in the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /// Encoded team id where the usergroup is, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl UsergroupsQuery for Disable<'_> {}
+
+impl Request for Disable<'_> {
+    type Response = UsergroupsDisable;
+
+    fn path(&self) -> &'static str {
+        "usergroups.disable"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsDisable {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The disabled usergroup.
+    pub usergroup: Option<Usergroup>,
+}
+
+impl Response for UsergroupsDisable {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
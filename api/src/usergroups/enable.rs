@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{TeamId, UsergroupId},
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    usergroups::{Usergroup, UsergroupsQuery},
+};
+
+/// A request for `usergroups.enable` API. Enables a usergroup.
+///
+/// See: https://api.slack.com/methods/usergroups.enable
+#[derive(Serialize, Debug, Clone)]
+pub struct Enable<'a> {
+    /// The usergroup to enable.
+    pub usergroup: &'a UsergroupId,
+    /// Include the number of users in the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /// Encoded team id where the usergroup is, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl UsergroupsQuery for Enable<'_> {}
+
+impl Request for Enable<'_> {
+    type Response = UsergroupsEnable;
+
+    fn path(&self) -> &'static str {
+        "usergroups.enable"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsEnable {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The enabled usergroup.
+    pub usergroup: Option<Usergroup>,
+}
+
+impl Response for UsergroupsEnable {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -1,6 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
-use crate::{request::Request, response::Response, usergroups::UsergroupsQuery};
+use crate::{
+    id::{ChannelId, TeamId, UserId, UsergroupId},
+    request::{comma_separated, Request},
+    response::Response,
+    usergroups::UsergroupsQuery,
+};
 
 /// A request for `usergroups.list` API. No parameters.
 ///
@@ -13,8 +18,9 @@ pub struct List {
     pub include_disabled: Option<bool>,
     /// Include the list of users for each User Group.
     pub include_users: Option<bool>,
-    /// The id of the usergroup you would like to filter the results down to.
-    pub usergroup_ids: Option<String>,
+    /// The IDs of the usergroups you would like to filter the results down to.
+    #[serde(serialize_with = "comma_separated")]
+    pub usergroup_ids: Option<Vec<UsergroupId>>,
 }
 
 impl UsergroupsQuery for List {}
@@ -30,26 +36,93 @@ impl Request for List {
 #[derive(Deserialize, Debug, Clone)]
 pub struct UsergroupsList {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     pub usergroups: Option<Vec<Usergroup>>,
 }
 impl Response for UsergroupsList {
     fn is_ok(&self) -> bool {
         self.ok
     }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Usergroup {
     /// The ID of the usergroup.
-    pub id: String,
+    pub id: UsergroupId,
     /// Team ID
-    pub team_id: String,
+    pub team_id: TeamId,
     /// The name of the usergroup.
     pub name: String,
     /// The description of the usergroup.
     pub description: Option<String>,
-    /// The name of the usergroup.
+    /// The mention handle of the usergroup.
     pub handle: String,
-    /// Number of users
+    /// Whether the usergroup is shared with an external organization.
+    #[serde(default)]
+    pub is_external: bool,
+    /// When the usergroup was created, in Unix time.
+    #[serde(default)]
+    pub date_create: i64,
+    /// When the usergroup was last updated, in Unix time.
+    #[serde(default)]
+    pub date_update: i64,
+    /// When the usergroup was disabled, in Unix time, or `0` if it is enabled.
+    #[serde(default)]
+    pub date_delete: i64,
+    /// The user who created the usergroup.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub created_by: Option<UserId>,
+    /// The user who last updated the usergroup.
+    #[serde(default, deserialize_with = "crate::id::empty_as_none")]
+    pub updated_by: Option<UserId>,
+    /// The default channels and groups of the usergroup.
+    pub prefs: Option<UsergroupPrefs>,
+    /// The members of the usergroup, if requested with `include_users`.
+    #[serde(default)]
+    pub users: Vec<UserId>,
+    /// Number of users, if requested with `include_count`.
+    #[serde(default, deserialize_with = "number_or_string")]
     pub user_count: u64,
 }
+
+impl Usergroup {
+    /// Returns `true` if the usergroup is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.date_delete != 0
+    }
+}
+
+/// Deserialize a number which may be sent as a string, e.g. `user_count` in the response of
+/// `usergroups.create`.
+fn number_or_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u64),
+        String(String),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(n) => Ok(n),
+        NumberOrString::String(s) => s.parse().map_err(de::Error::custom),
+    }
+}
+
+/// The preferences of a usergroup.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupPrefs {
+    /// The channels members of the usergroup are added to by default.
+    #[serde(default)]
+    pub channels: Vec<ChannelId>,
+    /// The groups members of the usergroup are added to by default.
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
@@ -1,11 +1,23 @@
+mod create;
+mod disable;
+mod enable;
 mod list;
+mod update;
 mod users;
+mod users_update;
 
-pub use list::{List, Usergroup};
+pub use create::Create;
+pub use disable::Disable;
+pub use enable::Enable;
+pub use list::{List, Usergroup, UsergroupPrefs};
+pub use update::Update;
 pub use users::Users;
+pub use users_update::UsersUpdate;
 
 use crate::request::Request;
 
 /// A marker trait which denotes a request for the `usergroups` API.
 pub trait UsergroupsQuery: Request {}
-pub trait UsergroupsUsersQuery: Request {}
+/// A marker trait which denotes a request for the `usergroups.users` API, i.e. the members of a
+/// usergroup. Such a request is also a [`UsergroupsQuery`].
+pub trait UsergroupsUsersQuery: UsergroupsQuery {}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{ChannelId, TeamId, UsergroupId},
+    request::{comma_separated, Request, RequestEncoding, RequestMethod},
+    response::Response,
+    usergroups::{Usergroup, UsergroupsQuery},
+};
+
+/// A request for `usergroups.update` API. Start from [`Update::new`] and set the fields you need.
+///
+/// See: https://api.slack.com/methods/usergroups.update
+#[derive(Serialize, Debug, Clone)]
+pub struct Update<'a> {
+    /// The usergroup to update.
+    pub usergroup: &'a UsergroupId,
+    /// A name for the usergroup. Must be unique among usergroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    /// A mention handle. Must be unique among channels, users and usergroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<&'a str>,
+    /// A short description of the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    /// Channels used as a default for the usergroup.
+    #[serde(serialize_with = "comma_separated", skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<ChannelId>>,
+    /// Include the number of users in the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /// Encoded team id where the usergroup is, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl<'a> Update<'a> {
+    /// Create a new request to update the given usergroup, without any changes.
+    pub fn new(usergroup: &'a UsergroupId) -> Self {
+        Self {
+            usergroup,
+            name: None,
+            handle: None,
+            description: None,
+            channels: None,
+            include_count: None,
+            team_id: None,
+        }
+    }
+}
+
+impl UsergroupsQuery for Update<'_> {}
+
+impl Request for Update<'_> {
+    type Response = UsergroupsUpdate;
+
+    fn path(&self) -> &'static str {
+        "usergroups.update"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsUpdate {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The updated usergroup.
+    pub usergroup: Option<Usergroup>,
+}
+
+impl Response for UsergroupsUpdate {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{request::Request, response::Response, usergroups::UsergroupsQuery};
+use crate::{
+    id::{UserId, UsergroupId},
+    request::Request,
+    response::Response,
+    usergroups::{UsergroupsQuery, UsergroupsUsersQuery},
+};
 
 /// A request for `usergroups.users.list` API.
 ///
@@ -9,11 +14,13 @@ use crate::{request::Request, response::Response, usergroups::UsergroupsQuery};
 pub struct Users {
     /// The ID of the usergroup.
     #[serde(rename = "usergroup")]
-    pub id: String,
+    pub id: UsergroupId,
 }
 
 impl UsergroupsQuery for Users {}
 
+impl UsergroupsUsersQuery for Users {}
+
 impl Request for Users {
     type Response = UsergroupsUsers;
 
@@ -25,10 +32,16 @@ impl Request for Users {
 #[derive(Deserialize, Debug, Clone)]
 pub struct UsergroupsUsers {
     pub ok: bool,
-    pub users: Option<Vec<String>>,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub users: Option<Vec<UserId>>,
 }
 impl Response for UsergroupsUsers {
     fn is_ok(&self) -> bool {
         self.ok
     }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
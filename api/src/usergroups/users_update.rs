@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{TeamId, UserId, UsergroupId},
+    request::{comma_separated_list, Request, RequestEncoding, RequestMethod},
+    response::Response,
+    usergroups::{Usergroup, UsergroupsQuery, UsergroupsUsersQuery},
+};
+
+/// A request for `usergroups.users.update` API, which replaces the members of a usergroup.
+///
+/// See: https://api.slack.com/methods/usergroups.users.update
+#[derive(Serialize, Debug, Clone)]
+pub struct UsersUpdate<'a> {
+    /// The usergroup to update.
+    pub usergroup: &'a UsergroupId,
+    /// The entire list of the users for the usergroup, which can't be empty.
+    #[serde(serialize_with = "comma_separated_list")]
+    pub users: Vec<UserId>,
+    /// Include the number of users in the usergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /// Encoded team id where the usergroup is, required if org token is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl UsergroupsUsersQuery for UsersUpdate<'_> {}
+
+impl UsergroupsQuery for UsersUpdate<'_> {}
+
+impl Request for UsersUpdate<'_> {
+    type Response = UsergroupsUsersUpdate;
+
+    fn path(&self) -> &'static str {
+        "usergroups.users.update"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsUsersUpdate {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The updated usergroup.
+    pub usergroup: Option<Usergroup>,
+}
+
+impl Response for UsergroupsUsersUpdate {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
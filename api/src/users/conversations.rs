@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversations::{from_channel_types, Channel, ChannelTypes},
+    id::{TeamId, UserId},
+    request::{Paginated, Request},
+    response::{Response, ResponseMetadata},
+    users::UsersQuery,
+};
+
+/// A request for `users.conversations` API, which lists conversations the user may access.
+///
+/// See: https://api.slack.com/methods/users.conversations
+#[derive(Serialize, Debug, Clone)]
+pub struct Conversations<'a> {
+    /// Browse conversations by a specific user ID's membership. Defaults to the authed user.
+    pub user: Option<&'a UserId>,
+    /// Mix and match channel types by providing a comma-separated list of any combination of
+    /// public_channel, private_channel, mpim, im
+    #[serde(serialize_with = "from_channel_types")]
+    pub types: Option<ChannelTypes>,
+    /// Set to true to exclude archived channels from the list.
+    pub exclude_archived: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor
+    /// attribute returned by a previous request's response_metadata. Default value fetches the
+    /// first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be
+    /// returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u64>,
+    /// Encoded team id to list conversations in, required if org token is used.
+    pub team_id: Option<&'a TeamId>,
+}
+
+impl UsersQuery for Conversations<'_> {}
+
+impl Request for Conversations<'_> {
+    type Response = UsersConversations;
+
+    fn path(&self) -> &'static str {
+        "users.conversations"
+    }
+}
+
+impl Paginated for Conversations<'_> {
+    type Item = Channel;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.channels.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersConversations {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    pub channels: Option<Vec<Channel>>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl Response for UsersConversations {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn next_cursor(&self) -> Option<String> {
+        self.response_metadata.as_ref().and_then(|m| {
+            if m.next_cursor.is_empty() {
+                return None;
+            }
+            Some(m.next_cursor.clone())
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{id::UserId, request::Request, response::Response, users::UsersQuery};
+
+/// A request for `users.getPresence` API.
+///
+/// See: https://api.slack.com/methods/users.getPresence
+#[derive(Serialize, Debug, Clone)]
+pub struct GetPresence<'a> {
+    /// User to get presence info on. Defaults to the authed user.
+    pub user: Option<&'a UserId>,
+}
+
+impl UsersQuery for GetPresence<'_> {}
+
+impl Request for GetPresence<'_> {
+    type Response = UsersGetPresence;
+
+    fn path(&self) -> &'static str {
+        "users.getPresence"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersGetPresence {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The presence of the user.
+    pub presence: Option<Presence>,
+    /// Whether the user has an active connection, only for the authed user.
+    pub online: Option<bool>,
+    /// Whether the user is away because of inactivity, only for the authed user.
+    pub auto_away: Option<bool>,
+    /// Whether the user has set their presence to away, only for the authed user.
+    pub manual_away: Option<bool>,
+    /// The number of connections of the user, only for the authed user.
+    pub connection_count: Option<u64>,
+    /// When the user was last active, in Unix time, only for the authed user.
+    pub last_activity: Option<i64>,
+}
+
+impl Response for UsersGetPresence {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// The presence of a user.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Presence {
+    Active,
+    Away,
+}
+
+impl AsRef<str> for Presence {
+    fn as_ref(&self) -> &str {
+        match self {
+            Presence::Active => "active",
+            Presence::Away => "away",
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use crate::{request::Request, response::Response, users::UsersQuery};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::{
+    id::{TeamId, UserId},
+    request::Request,
+    response::Response,
+    users::UsersQuery,
+};
 
 /// A request for `users.info` API.
 ///
@@ -9,7 +17,7 @@ use crate::{request::Request, response::Response, users::UsersQuery};
 pub struct Info<'a> {
     /// User ID to get info on
     #[serde(rename = "user")]
-    pub id: &'a str,
+    pub id: &'a UserId,
 }
 impl UsersQuery for Info<'_> {}
 impl Request for Info<'_> {
@@ -23,6 +31,8 @@ impl Request for Info<'_> {
 #[derive(Deserialize, Debug, Clone)]
 pub struct UsersInfo {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     /// The user object.
     pub user: Option<User>,
 }
@@ -30,14 +40,18 @@ impl Response for UsersInfo {
     fn is_ok(&self) -> bool {
         self.ok
     }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct User {
     /// The user ID.
-    pub id: String,
+    pub id: UserId,
     /// The team ID.
-    pub team_id: String,
+    pub team_id: TeamId,
     /// The name of the user.
     pub name: String,
     /// The real name of the user.
@@ -77,4 +91,47 @@ pub struct Profile {
     pub image_512: Option<String>,
     pub image_1024: Option<String>,
     pub image_original: Option<String>,
+    /// The status text, e.g. `riding a train`.
+    pub status_text: Option<String>,
+    /// The status emoji, with colons, e.g. `:train:`.
+    pub status_emoji: Option<String>,
+    /// When the status expires, in Unix time, or `0` if it never expires.
+    pub status_expiration: Option<i64>,
+    /// The phone number of the user.
+    pub phone: Option<String>,
+    /// The pronouns of the user.
+    pub pronouns: Option<String>,
+    /// The start date of the user, in `YYYY-MM-DD`, which is set by the admin.
+    pub start_date: Option<String>,
+    /// Custom profile fields, keyed by the field ID, e.g. `Xf06054BBB`.
+    #[serde(default, deserialize_with = "profile_fields")]
+    pub fields: HashMap<String, ProfileField>,
+}
+
+/// Deserialize custom profile fields, which are `null` or an empty array if there are none.
+fn profile_fields<'de, D>(deserializer: D) -> Result<HashMap<String, ProfileField>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Object(fields)) => {
+            serde_json::from_value(Value::Object(fields)).map_err(de::Error::custom)
+        }
+        Some(Value::Array(fields)) if fields.is_empty() => Ok(HashMap::new()),
+        None => Ok(HashMap::new()),
+        Some(other) => Err(de::Error::custom(format!("invalid profile fields: {other}"))),
+    }
+}
+
+/// A custom profile field of a user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProfileField {
+    /// The value of the field.
+    pub value: String,
+    /// The text to show instead of the value, e.g. the title of a link.
+    #[serde(default)]
+    pub alt: String,
+    /// The label of the field, only if requested with `include_labels` of `users.profile.get`.
+    #[serde(skip_serializing)]
+    pub label: Option<String>,
 }
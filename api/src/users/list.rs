@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    request::Request,
+    request::{Paginated, Request},
     response::{Response, ResponseMetadata},
     users::{info::User, UsersQuery},
 };
@@ -27,10 +27,23 @@ impl Request for List {
         "users.list"
     }
 }
+impl Paginated for List {
+    type Item = User;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response.members.as_deref().unwrap_or_default()
+    }
+}
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct UsersList {
     pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
     pub members: Option<Vec<User>>,
     pub response_metadata: Option<ResponseMetadata>,
 }
@@ -39,6 +52,10 @@ impl Response for UsersList {
         self.ok
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn next_cursor(&self) -> Option<String> {
         self.response_metadata.as_ref().and_then(|m| {
             if m.next_cursor.is_empty() {
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    request::Request,
+    response::Response,
+    users::{User, UsersQuery},
+};
+
+/// A request for `users.lookupByEmail` API.
+///
+/// See: https://api.slack.com/methods/users.lookupByEmail
+#[derive(Serialize, Debug, Clone)]
+pub struct LookupByEmail<'a> {
+    /// An email address belonging to a user in the workspace.
+    pub email: &'a str,
+}
+
+impl UsersQuery for LookupByEmail<'_> {}
+
+impl Request for LookupByEmail<'_> {
+    type Response = UsersLookupByEmail;
+
+    fn path(&self) -> &'static str {
+        "users.lookupByEmail"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersLookupByEmail {
+    pub ok: bool,
+    /// The error code, if the request failed, e.g. `users_not_found`.
+    pub error: Option<String>,
+    /// The user object.
+    pub user: Option<User>,
+}
+
+impl Response for UsersLookupByEmail {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -1,8 +1,20 @@
+mod conversations;
+mod get_presence;
 mod info;
 mod list;
+mod lookup_by_email;
+mod profile_get;
+mod profile_set;
+mod set_presence;
 
-pub use info::{Info, User};
+pub use conversations::Conversations;
+pub use get_presence::{GetPresence, Presence};
+pub use info::{Info, Profile, ProfileField, User};
 pub use list::List;
+pub use lookup_by_email::LookupByEmail;
+pub use profile_get::ProfileGet;
+pub use profile_set::{ProfileSet, ProfileUpdate};
+pub use set_presence::{PresenceSetting, SetPresence};
 
 use crate::request::Request;
 
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::UserId,
+    request::Request,
+    response::Response,
+    users::{Profile, UsersQuery},
+};
+
+/// A request for `users.profile.get` API.
+///
+/// See: https://api.slack.com/methods/users.profile.get
+#[derive(Serialize, Debug, Clone)]
+pub struct ProfileGet<'a> {
+    /// User to retrieve profile info for. Defaults to the authed user.
+    pub user: Option<&'a UserId>,
+    /// Include labels for each ID in custom profile fields.
+    pub include_labels: Option<bool>,
+}
+
+impl UsersQuery for ProfileGet<'_> {}
+
+impl Request for ProfileGet<'_> {
+    type Response = UsersProfileGet;
+
+    fn path(&self) -> &'static str {
+        "users.profile.get"
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersProfileGet {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The profile of the user.
+    pub profile: Option<Profile>,
+}
+
+impl Response for UsersProfileGet {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::UserId,
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    users::{Profile, ProfileField, UsersQuery},
+};
+
+/// A request for `users.profile.set` API. Only the fields set in `profile` are changed.
+///
+/// See: https://api.slack.com/methods/users.profile.set
+#[derive(Serialize, Debug, Clone)]
+pub struct ProfileSet<'a> {
+    /// ID of user to change. This argument may only be specified by admins on paid teams. Defaults
+    /// to the authed user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<&'a UserId>,
+    /// The fields of the profile to change.
+    pub profile: ProfileUpdate<'a>,
+}
+
+impl UsersQuery for ProfileSet<'_> {}
+
+impl Request for ProfileSet<'_> {
+    type Response = UsersProfileSet;
+
+    fn path(&self) -> &'static str {
+        "users.profile.set"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+/// The fields of a profile to change. Fields left `None` are kept as they are, and an empty string
+/// clears the field.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ProfileUpdate<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub real_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<&'a str>,
+    /// The status emoji, with colons, e.g. `:train:`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<&'a str>,
+    /// When the status expires, in Unix time. `0` for no expiration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_expiration: Option<i64>,
+    /// Custom profile fields, keyed by the field ID, e.g. `Xf06054BBB`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<HashMap<String, ProfileField>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersProfileSet {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+    /// The updated profile.
+    pub profile: Option<Profile>,
+}
+
+impl Response for UsersProfileSet {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
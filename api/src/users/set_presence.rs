@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    request::{Request, RequestEncoding, RequestMethod},
+    response::Response,
+    users::UsersQuery,
+};
+
+/// A request for `users.setPresence` API, which manually sets the presence of the authed user.
+///
+/// See: https://api.slack.com/methods/users.setPresence
+#[derive(Serialize, Debug, Clone)]
+pub struct SetPresence {
+    /// The presence to set.
+    pub presence: PresenceSetting,
+}
+
+impl UsersQuery for SetPresence {}
+
+impl Request for SetPresence {
+    type Response = UsersSetPresence;
+
+    fn path(&self) -> &'static str {
+        "users.setPresence"
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn encoding(&self) -> RequestEncoding {
+        RequestEncoding::Json
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersSetPresence {
+    pub ok: bool,
+    /// The error code, if the request failed.
+    pub error: Option<String>,
+}
+
+impl Response for UsersSetPresence {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// A presence which can be set manually.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceSetting {
+    /// Let Slack decide by the activity of the user.
+    Auto,
+    /// Always away.
+    Away,
+}
@@ -1,8 +1,20 @@
-use anyhow::{anyhow, Result};
-use clap::Parser;
-use jiff::{civil::Date, tz::TimeZone, Timestamp};
-use slack_client::{conversations, usergroups, users, Response};
-use url::Url;
+use std::{collections::BTreeSet, path::PathBuf};
+
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use futures::TryStreamExt;
+use jiff::{
+    civil::{Date, DateTime},
+    tz::TimeZone,
+    Timestamp,
+};
+use serde::Deserialize;
+use slack_client::{
+    chat, conversations,
+    id::{ChannelId, UserId, UsergroupId},
+    message_retriever::{RetrieverSession, Scope},
+    usergroups, users, ApiClient, SlackPermalink, Ts,
+};
 
 #[derive(Parser)]
 pub struct Args {
@@ -20,7 +32,7 @@ pub enum Command {
     Channels {
         /// Only list channels created by a specified user ID
         #[arg(long)]
-        creator: Option<String>,
+        creator: Option<UserId>,
 
         /// Only list not archived channels
         #[arg(long)]
@@ -31,21 +43,52 @@ pub enum Command {
     ChannelLastMessage {
         /// Channel ID
         #[arg(required = true)]
-        channel: String,
+        channel: ChannelId,
+    },
+
+    /// Dump members of a given channel as CSV or JSON
+    ChannelMembers {
+        /// Channel ID
+        #[arg(required = true)]
+        channel: ChannelId,
+        /// Output format.
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
     },
 
     /// Get messages
     Messages {
         /// Conversation ID to fetch history for.
         #[arg(required = true)]
-        channel: String,
+        channel: ChannelId,
         /// Only messages after this YYYY-MM-DD will be fetched.
         #[arg(required = true)]
         oldest: String,
         /// Only messages before this YYYY-MM-DD will be fetched.
         #[arg(required = true)]
         latest: String,
-        /// The IANA time zone database identifiers to use for the timest
+        /// The IANA time zone identifier, e.g. `Asia/Tokyo`, to read the dates in and to show the
+        /// times of the messages in.
+        #[arg(long, default_value = "Asia/Tokyo")]
+        time_zone: String,
+        /// Skip notifications of members joining or leaving the channel.
+        #[arg(long)]
+        skip_join_leave: bool,
+    },
+
+    /// Schedule a message to be posted later
+    Schedule {
+        /// Channel ID to post the message to.
+        #[arg(required = true)]
+        channel: ChannelId,
+        /// The date and time to post the message at, in YYYY-MM-DD HH:MM.
+        #[arg(required = true)]
+        post_at: String,
+        /// The text of the message.
+        #[arg(required = true)]
+        text: String,
+        /// The IANA time zone identifier, e.g. `Asia/Tokyo`, in which the date and time to post
+        /// the message at is given.
         #[arg(long, default_value = "Asia/Tokyo")]
         time_zone: String,
     },
@@ -55,9 +98,40 @@ pub enum Command {
         /// Link to the thread
         #[arg(required = true)]
         url: String,
-        /// The IANA time zone database identifiers to use for the timest
+        /// The IANA time zone identifier, e.g. `Asia/Tokyo`, to show the times of the messages in.
         #[arg(long, default_value = "Asia/Tokyo")]
         time_zone: String,
+        /// Skip notifications of members joining or leaving the channel.
+        #[arg(long)]
+        skip_join_leave: bool,
+    },
+
+    /// Print a digest of unread messages in the channels you are a member of as Markdown. Requires
+    /// a user token, as read markers are per user.
+    Unread {
+        /// Mark the channels as read up to the printed messages.
+        #[arg(long)]
+        mark: bool,
+        /// The maximum number of messages to print per channel, which are the latest ones. Older
+        /// unread messages are skipped, but marked as read with `--mark` as well.
+        #[arg(long, default_value_t = 100)]
+        max_messages: usize,
+    },
+
+    /// Upload a file, and share it to a channel
+    Upload {
+        /// Channel ID to share the file to.
+        #[arg(required = true)]
+        channel: ChannelId,
+        /// Path to the file to upload.
+        #[arg(required = true)]
+        path: PathBuf,
+        /// Timestamp of the parent message, to share the file in the thread.
+        #[arg(long)]
+        thread_ts: Option<Ts>,
+        /// The message text introducing the file.
+        #[arg(long)]
+        comment: Option<String>,
     },
 
     /// Dump a list of Slack user groups as JSON
@@ -67,11 +141,119 @@ pub enum Command {
     UsergroupUsers {
         /// User group ID
         #[arg(required = true)]
-        usergroup: String,
+        usergroup: UsergroupId,
+    },
+
+    /// Dump channels a given user is a member of as CSV or JSON
+    UserChannels {
+        /// User ID
+        #[arg(required = true)]
+        user: UserId,
+        /// Only list not archived channels
+        #[arg(long)]
+        exclude_archived: bool,
+        /// Output format.
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+    },
+
+    /// Sync members of user groups with a JSON roster, creating or enabling the groups as needed
+    UsergroupSync {
+        /// Path to the roster, e.g. `{"usergroups": [{"handle": "oncall", "members": ["U…"]}]}`.
+        /// Each group has `handle`, `members` (user IDs), and optional `name` and `description`.
+        #[arg(required = true)]
+        roster: PathBuf,
+        /// Only print the changes, without applying them.
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Dump users
     Users,
+
+    /// Show the profile and presence of a user
+    Whois {
+        /// Email address, `@name`, or user ID of the user.
+        #[arg(required = true)]
+        query: String,
+    },
+}
+
+/// A roster of user groups for `usergroup-sync`.
+#[derive(Deserialize)]
+pub struct Roster {
+    pub usergroups: Vec<RosterUsergroup>,
+}
+
+impl Roster {
+    /// Returns the problems of each invalid group, keyed by the handle. A group must have members,
+    /// as an empty group should be disabled instead, and a handle must appear only once.
+    pub fn validate(&self) -> Vec<(&str, String)> {
+        let mut handles = BTreeSet::new();
+        let mut problems = vec![];
+        for group in &self.usergroups {
+            let handle = group.handle.as_str();
+            if !handles.insert(handle) {
+                problems.push((handle, format!("@{handle} appears more than once")));
+            } else if group.members.is_empty() {
+                problems.push((handle, format!("@{handle} has no members. Disable it instead.")));
+            }
+        }
+        problems
+    }
+}
+
+/// A user group in the roster.
+#[derive(Deserialize)]
+pub struct RosterUsergroup {
+    /// The mention handle, which identifies the group.
+    pub handle: String,
+    /// The name of the group. Defaults to the handle when the group is created.
+    pub name: Option<String>,
+    /// The description of the group.
+    pub description: Option<String>,
+    /// User IDs of the members.
+    pub members: Vec<UserId>,
+}
+
+/// The changes to apply to a user group to match the roster.
+#[derive(Debug, Default, PartialEq)]
+pub struct UsergroupChanges<'a> {
+    /// The group doesn't exist yet.
+    pub create: bool,
+    /// The group is disabled.
+    pub enable: bool,
+    /// The name or the description differs.
+    pub update: bool,
+    pub added: Vec<&'a UserId>,
+    pub removed: Vec<&'a UserId>,
+}
+
+impl<'a> UsergroupChanges<'a> {
+    /// Compare the group in the roster with the current one, if it exists.
+    pub fn new(group: &'a RosterUsergroup, current: Option<&'a usergroups::Usergroup>) -> Self {
+        let live = current
+            .map(|g| g.users.iter().collect::<BTreeSet<_>>())
+            .unwrap_or_default();
+        let members = group.members.iter().collect::<BTreeSet<_>>();
+        Self {
+            create: current.is_none(),
+            enable: current.is_some_and(|g| g.is_disabled()),
+            update: current.is_some_and(|g| {
+                group.name.as_ref().is_some_and(|name| name != &g.name)
+                    || group.description.is_some() && group.description != g.description
+            }),
+            added: members.difference(&live).copied().collect(),
+            removed: live.difference(&members).copied().collect(),
+        }
+    }
+}
+
+/// Output format of the membership commands.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
 }
 
 #[tokio::main]
@@ -81,23 +263,11 @@ async fn main() -> Result<()> {
 
     match command {
         Command::Users => {
-            let mut results = vec![];
-            let mut request = users::List { cursor: None, limit: Some(1000) };
-
-            loop {
-                let users = client.users(&request).await?;
-                let cursor = users.next_cursor();
-
-                if let Some(members) = users.members {
-                    results.extend(members)
-                }
-
-                if cursor.is_some() {
-                    request.cursor = cursor;
-                } else {
-                    break;
-                }
-            }
+            let results = client
+                .paginate(users::List { cursor: None, limit: Some(1000) })
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
             println!("{:#?}", results);
         }
         Command::Usergroups => {
@@ -122,67 +292,178 @@ async fn main() -> Result<()> {
                 }
             }
         }
-        Command::Messages { ref channel, ref oldest, ref latest, ref time_zone } => {
-            let ymd_to_f64 = |s: &str| -> Result<f64> {
+        Command::Messages {
+            ref channel,
+            ref oldest,
+            ref latest,
+            ref time_zone,
+            skip_join_leave,
+        } => {
+            let ymd_to_ts = |s: &str| -> Result<Ts> {
                 let dt = Date::strptime("%Y-%m-%d", s)?.to_zoned(TimeZone::get(time_zone)?)?;
-                Ok(Timestamp::from(dt).as_second() as f64)
+                Ok(Timestamp::from(dt).into())
             };
 
             let messages = client
-                .conversations(&conversations::History {
+                .paginate(conversations::History {
                     channel,
-                    oldest: Some(ymd_to_f64(oldest)?),
-                    latest: Some(ymd_to_f64(latest)?),
+                    oldest: Some(ymd_to_ts(oldest)?),
+                    latest: Some(ymd_to_ts(latest)?),
                     limit: Some(1000),
                     inclusive: Some(true),
                     cursor: None,
                 })
-                .await?
-                .messages;
-
-            if let Some(messages) = messages {
-                for m in messages {
-                    println!(
-                        "# {} {}",
-                        ts_to_datetime(&m.ts, time_zone)?,
-                        m.text.unwrap_or_default()
-                    );
-                }
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            for m in messages
+                .into_iter()
+                .filter(|m| !(skip_join_leave && is_join_or_leave(m)))
+            {
+                println!(
+                    "# {}{} {}",
+                    ts_to_datetime(&m.ts, time_zone)?,
+                    edited_mark(&m),
+                    m.text.unwrap_or_default()
+                );
             }
         }
-        Command::ThreadMessages { ref url, ref time_zone } => {
-            let url = Url::parse(url)?;
-            let (channel, ts) = parse(&url)?;
+        Command::Schedule { ref channel, ref post_at, ref text, ref time_zone } => {
+            let post_at = DateTime::strptime("%Y-%m-%d %H:%M", post_at)?
+                .to_zoned(TimeZone::get(time_zone)?)?;
+
+            let response = client
+                .chat(&chat::ScheduleMessage {
+                    text: Some(text),
+                    ..chat::ScheduleMessage::new(channel, post_at.timestamp().as_second())
+                })
+                .await?;
+
+            println!(
+                "Scheduled {} at {}",
+                response.scheduled_message_id.unwrap_or_default(),
+                post_at.strftime("%Y-%m-%d %H:%M:%S (%Z)")
+            );
+        }
+        Command::ThreadMessages { ref url, ref time_zone, skip_join_leave } => {
+            let SlackPermalink { channel, ts, thread_ts, .. } = url.parse()?;
             let messages = client
-                .conversations(&conversations::Replies {
-                    channel,
-                    ts,
+                .paginate(conversations::Replies {
+                    channel: &channel,
+                    ts: thread_ts.unwrap_or(ts),
                     latest: None,
                     oldest: None,
                     limit: None,
                     inclusive: Some(true),
+                    cursor: None,
                 })
-                .await?
-                .messages;
-
-            if let Some(messages) = messages {
-                for m in messages {
-                    println!(
-                        "# {}\n\n{}\n",
-                        ts_to_datetime(&m.ts, time_zone)?,
-                        m.blocks
-                            .unwrap_or_default()
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<String>>()
-                            .join("\n")
-                    );
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            for m in messages
+                .into_iter()
+                .filter(|m| !(skip_join_leave && is_join_or_leave(m)))
+            {
+                println!(
+                    "# {}{}\n\n{}\n",
+                    ts_to_datetime(&m.ts, time_zone)?,
+                    edited_mark(&m),
+                    m.blocks
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                );
+            }
+        }
+        Command::Unread { mark, max_messages } => {
+            let channels = client
+                .paginate(users::Conversations {
+                    user: None,
+                    types: Some(
+                        vec![
+                            conversations::ChannelType::Public,
+                            conversations::ChannelType::Private,
+                            conversations::ChannelType::Mpim,
+                            conversations::ChannelType::Im,
+                        ]
+                        .into(),
+                    ),
+                    exclude_archived: Some(true),
+                    cursor: None,
+                    limit: Some(1000),
+                    team_id: None,
+                })
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
+            let session =
+                RetrieverSession::new(client.clone()).scope(Scope::After(Some(max_messages)));
+
+            // Report a channel which fails, and go on with the others.
+            for channel in channels {
+                let result = async {
+                    let Some(info) = client
+                        .conversations(&conversations::Info::new(&channel.id))
+                        .await?
+                        .channel
+                    else {
+                        return Ok(());
+                    };
+                    let Some(last_read) = info.last_read else {
+                        return Ok(()); // Not available with a bot token
+                    };
+                    if info.unread_count == Some(0) {
+                        return Ok(());
+                    }
+
+                    let resolved = session
+                        .retriever_at(channel.id.clone(), last_read)
+                        .resolve(true)
+                        .await?;
+                    let Some(latest) = resolved.messages.last() else {
+                        return Ok(());
+                    };
+
+                    println!("## {}\n\n{}\n", resolved.channel_name, resolved.transcript());
+                    if mark {
+                        client
+                            .conversations(&conversations::Mark {
+                                channel: &channel.id,
+                                ts: latest.ts,
+                            })
+                            .await?;
+                    }
+                    Ok::<_, anyhow::Error>(())
+                }
+                .await;
+
+                if let Err(e) = result {
+                    let name = channel.name.as_deref().unwrap_or(channel.id.as_str());
+                    eprintln!("Failed to get unread messages of {name}: {e}");
                 }
             }
         }
+        Command::Upload { ref channel, ref path, thread_ts, ref comment } => {
+            let filename = match path.file_name() {
+                Some(name) => name.to_string_lossy(),
+                None => anyhow::bail!("Not a file: {}", path.display()),
+            };
+            let content = tokio::fs::read(path).await?;
+
+            let files = client
+                .upload_file(&filename, content, Some(channel), thread_ts, comment.as_deref())
+                .await?;
+
+            for file in files {
+                println!("Uploaded {} {}", file.id, file.permalink.unwrap_or_default());
+            }
+        }
         Command::Channels { creator, exclude_archived } => {
-            let mut results = vec![];
-            let mut request = conversations::List {
+            let request = conversations::List {
                 exclude_archived: Some(true),
                 types: Some(
                     vec![
@@ -197,20 +478,7 @@ async fn main() -> Result<()> {
                 limit: Some(1000),
             };
 
-            loop {
-                let channels = client.conversations(&request).await?;
-                let cursor = channels.next_cursor();
-
-                if let Some(channels) = channels.channels {
-                    results.extend(channels)
-                }
-
-                if cursor.is_some() {
-                    request.cursor = cursor;
-                } else {
-                    break;
-                }
-            }
+            let mut results = client.paginate(request).items().try_collect::<Vec<_>>().await?;
 
             if let Some(creator) = creator {
                 results.retain(|c| match c.creator {
@@ -242,44 +510,360 @@ async fn main() -> Result<()> {
                 }
             }
         }
+        Command::ChannelMembers { ref channel, format } => {
+            let members = client
+                .paginate(conversations::Members { channel, cursor: None, limit: Some(1000) })
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let mut rows = vec![];
+            for ref id in members {
+                if let Some(user) = client.users(&users::Info { id }).await?.user {
+                    rows.push(vec![
+                        user.id.to_string(),
+                        user.name,
+                        user.profile.real_name_normalized,
+                    ]);
+                }
+            }
+            print_rows(format, &["id", "name", "real_name"], rows)?;
+        }
+        Command::UserChannels { ref user, exclude_archived, format } => {
+            let channels = client
+                .paginate(users::Conversations {
+                    user: Some(user),
+                    types: Some(
+                        vec![
+                            conversations::ChannelType::Public,
+                            conversations::ChannelType::Private,
+                        ]
+                        .into(),
+                    ),
+                    exclude_archived: Some(exclude_archived),
+                    cursor: None,
+                    limit: Some(1000),
+                    team_id: None,
+                })
+                .items()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let mut rows = vec![];
+            for conversations::Channel { ref id, .. } in channels {
+                let response = client.conversations(&conversations::Info::new(id)).await?;
+                if let Some(channel) = response.channel {
+                    rows.push(vec![
+                        channel.id.to_string(),
+                        channel.name.unwrap_or_default(),
+                        channel.is_private.unwrap_or_default().to_string(),
+                        channel.is_archived.unwrap_or_default().to_string(),
+                    ]);
+                }
+            }
+            print_rows(format, &["id", "name", "is_private", "is_archived"], rows)?;
+        }
+        Command::UsergroupSync { ref roster, dry_run } => {
+            let roster: Roster = serde_json::from_str(&tokio::fs::read_to_string(roster).await?)?;
+            let live = client
+                .usergroups(&usergroups::List {
+                    include_count: None,
+                    include_disabled: Some(true),
+                    include_users: Some(true),
+                    usergroup_ids: None,
+                })
+                .await?
+                .usergroups
+                .unwrap_or_default();
+
+            // Skip the invalid groups, and report a group which fails and go on with the others.
+            let problems = roster.validate();
+            for (_, problem) in &problems {
+                eprintln!("{problem}");
+            }
+            let mut failed = 0;
+            for group in &roster.usergroups {
+                if problems.iter().any(|(handle, _)| *handle == group.handle) {
+                    failed += 1;
+                    continue;
+                }
+                let current = live.iter().find(|g| g.handle == group.handle);
+                if let Err(e) = sync_usergroup(&client, group, current, dry_run).await {
+                    eprintln!("Failed to sync @{}: {e}", group.handle);
+                    failed += 1;
+                }
+            }
+            if failed > 0 {
+                anyhow::bail!("{failed} of {} user groups not synced", roster.usergroups.len());
+            }
+        }
+        Command::Whois { ref query } => {
+            let user = if let Some(name) = query.strip_prefix('@') {
+                client
+                    .paginate(users::List { cursor: None, limit: Some(1000) })
+                    .items()
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .find(|u| u.name == name || u.profile.display_name == name)
+            } else if query.contains('@') {
+                client.users(&users::LookupByEmail { email: query }).await?.user
+            } else {
+                client.users(&users::Info { id: &query.parse()? }).await?.user
+            };
+            let Some(users::User { id, name, profile, tz, .. }) = user else {
+                anyhow::bail!("User not found: {query}");
+            };
+            let presence = client.users(&users::GetPresence { user: Some(&id) }).await?.presence;
+
+            let status = [profile.status_emoji.as_deref(), profile.status_text.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mut rows = vec![
+                ("id", id.to_string()),
+                ("name", name),
+                ("real_name", profile.real_name),
+                ("display_name", profile.display_name),
+                ("title", profile.title),
+                ("email", profile.email.unwrap_or_default()),
+                ("phone", profile.phone.unwrap_or_default()),
+                ("pronouns", profile.pronouns.unwrap_or_default()),
+                ("start_date", profile.start_date.unwrap_or_default()),
+                ("status", status),
+                ("presence", presence.map(|p| p.as_ref().to_string()).unwrap_or_default()),
+                ("tz", tz.unwrap_or_default()),
+            ];
+            let mut fields = profile.fields.into_iter().collect::<Vec<_>>();
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+            for (field_id, field) in fields {
+                rows.push(("field", format!("{field_id} {}", field.value)));
+            }
+
+            for (key, value) in rows.into_iter().filter(|(_, v)| !v.is_empty()) {
+                println!("{key}: {value}");
+            }
+        }
         _ => unimplemented!(),
     }
     Ok(())
 }
 
-/// Parse the given URL and return the channel ID, timestamp, and thread timestamp.
-///
-/// # Arguments
-///
-/// - `url` - The URL to parse.
-///
-/// # Returns
-///
-/// A tuple containing the channel ID (from path segments), timestamp in f64 (parsed the timestamp
-/// as f64)
-fn parse(url: &Url) -> Result<(&str, f64)> {
-    let channel_id = url
-        .path_segments()
-        .ok_or(anyhow!("Failed to get path segments"))?
-        .nth(1)
-        .ok_or(anyhow!("Failed to get the last path segment"))?;
-
-    let ts = url
-        .path_segments()
-        .ok_or(anyhow!("Failed to get path segments"))?
-        .last()
-        .ok_or(anyhow!("Failed to get the last path segment"))?;
-
-    let num = ts.trim_start_matches(|c: char| !c.is_numeric());
-    let (int_part, decimal_part) = num.split_at(num.len() - 6);
-    let ts64 = format!("{int_part}.{decimal_part}").parse::<f64>()?;
-
-    Ok((channel_id, ts64))
+/// Returns `true` if the message is a notification of a member joining or leaving.
+fn is_join_or_leave(message: &conversations::Message) -> bool {
+    message.subtype.as_ref().is_some_and(|s| s.is_join_or_leave())
+}
+
+/// Returns a mark to append to the header of an edited message.
+fn edited_mark(message: &conversations::Message) -> &'static str {
+    if message.is_edited() {
+        " (edited)"
+    } else {
+        ""
+    }
+}
+
+/// Apply the roster to the user group, or only print the changes if `dry_run`. The group is
+/// created if it doesn't exist yet, and enabled if it is disabled.
+async fn sync_usergroup(
+    client: &ApiClient,
+    group: &RosterUsergroup,
+    current: Option<&usergroups::Usergroup>,
+    dry_run: bool,
+) -> Result<()> {
+    let handle = &group.handle;
+    let changes = UsergroupChanges::new(group, current);
+
+    if changes.create {
+        println!("create @{handle}");
+    }
+    if changes.enable {
+        println!("enable @{handle}");
+    }
+    if changes.update {
+        println!("update @{handle}");
+    }
+    changes.added.iter().for_each(|id| println!("+ {id} to @{handle}"));
+    changes
+        .removed
+        .iter()
+        .for_each(|id| println!("- {id} from @{handle}"));
+    if dry_run {
+        return Ok(());
+    }
+
+    let id = match current {
+        Some(g) => g.id.clone(),
+        None => client
+            .usergroups(&usergroups::Create {
+                handle: Some(handle),
+                description: group.description.as_deref(),
+                ..usergroups::Create::new(group.name.as_deref().unwrap_or(handle))
+            })
+            .await?
+            .usergroup
+            .map(|g| g.id)
+            .ok_or_else(|| anyhow::anyhow!("Failed to create @{handle}"))?,
+    };
+    if changes.enable {
+        client
+            .usergroups(&usergroups::Enable { usergroup: &id, include_count: None, team_id: None })
+            .await?;
+    }
+    if changes.update {
+        client
+            .usergroups(&usergroups::Update {
+                name: group.name.as_deref(),
+                description: group.description.as_deref(),
+                ..usergroups::Update::new(&id)
+            })
+            .await?;
+    }
+    if !changes.added.is_empty() || !changes.removed.is_empty() {
+        client
+            .usergroups(&usergroups::UsersUpdate {
+                usergroup: &id,
+                users: group.members.clone(),
+                include_count: None,
+                team_id: None,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+/// Print the rows as CSV without a header, or as a JSON array of objects keyed by the columns.
+fn print_rows(format: Format, columns: &[&str], rows: Vec<Vec<String>>) -> Result<()> {
+    match format {
+        Format::Csv => {
+            for row in rows {
+                let row = row
+                    .iter()
+                    .map(|v| format!(r#""{}""#, v.replace('"', r#""""#)))
+                    .collect::<Vec<_>>();
+                println!("{}", row.join(","));
+            }
+        }
+        Format::Json => {
+            let rows = rows
+                .into_iter()
+                .map(|row| {
+                    let values = row.into_iter().map(serde_json::Value::from);
+                    columns.iter().map(|c| c.to_string()).zip(values).collect()
+                })
+                .collect::<Vec<serde_json::Map<_, _>>>();
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+    }
+    Ok(())
 }
 
 /// Convert the given timestamp to a datetime string.
-fn ts_to_datetime(s: &str, time_zone: &str) -> Result<String> {
-    let ts = (s.parse::<f64>()? * 1000000f64) as i64; // hacky
-    let ts = Timestamp::from_microsecond(ts)?.to_zoned(TimeZone::get(time_zone)?);
+fn ts_to_datetime(ts: &Ts, time_zone: &str) -> Result<String> {
+    let ts = ts.to_timestamp()?.to_zoned(TimeZone::get(time_zone)?);
     Ok(ts.strftime("%Y-%m-%d %H:%M:%S (%Z)").to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster(json: &str) -> Roster {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn usergroup(users: &[&str], date_delete: i64) -> usergroups::Usergroup {
+        serde_json::from_value(serde_json::json!({
+            "id": "S0123ABCD",
+            "team_id": "T0123ABCD",
+            "name": "On-call",
+            "description": "Who is on call",
+            "handle": "oncall",
+            "date_delete": date_delete,
+            "users": users,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_roster() {
+        let roster = roster(
+            r#"{"usergroups": [
+                {"handle": "oncall", "members": ["U0123ABCD", "W0123ABCD"]},
+                {"handle": "devs", "name": "Developers", "description": "All devs", "members": ["U0123ABCD"]}
+            ]}"#,
+        );
+        assert_eq!(roster.usergroups.len(), 2);
+        assert_eq!(roster.usergroups[0].name, None);
+        assert_eq!(roster.usergroups[1].name.as_deref(), Some("Developers"));
+        assert!(roster.validate().is_empty());
+
+        // Not a user ID.
+        assert!(serde_json::from_str::<Roster>(
+            r#"{"usergroups": [{"handle": "oncall", "members": ["C0123ABCD"]}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn validates_roster() {
+        let roster = roster(
+            r#"{"usergroups": [
+                {"handle": "empty", "members": []},
+                {"handle": "oncall", "members": ["U0123ABCD"]},
+                {"handle": "dup", "members": ["U0123ABCD"]},
+                {"handle": "dup", "members": ["W0123ABCD"]}
+            ]}"#,
+        );
+        let handles = roster
+            .validate()
+            .into_iter()
+            .map(|(handle, _)| handle)
+            .collect::<Vec<_>>();
+        assert_eq!(handles, vec!["empty", "dup"]);
+    }
+
+    #[test]
+    fn diffs_new_usergroup() {
+        let roster = roster(r#"{"usergroups": [{"handle": "oncall", "members": ["U0123ABCD"]}]}"#);
+        let changes = UsergroupChanges::new(&roster.usergroups[0], None);
+        assert!(changes.create);
+        assert!(!changes.enable && !changes.update);
+        assert_eq!(changes.added, vec![&"U0123ABCD".parse::<UserId>().unwrap()]);
+        assert!(changes.removed.is_empty());
+    }
+
+    #[test]
+    fn diffs_existing_usergroup() {
+        let roster = roster(
+            r#"{"usergroups": [
+                {"handle": "oncall", "members": ["U0000000A", "U0000000B"]},
+                {"handle": "oncall", "name": "On-call", "members": ["U0000000B", "U0000000C"]},
+                {"handle": "oncall", "name": "Support", "members": ["U0000000B", "U0000000C"]}
+            ]}"#,
+        );
+        let id = |s: &str| s.parse::<UserId>().unwrap();
+
+        // Same members and no name or description, in any order.
+        let current = usergroup(&["U0000000B", "U0000000A"], 0);
+        assert_eq!(
+            UsergroupChanges::new(&roster.usergroups[0], Some(&current)),
+            UsergroupChanges::default()
+        );
+
+        // Members changed, and the group is disabled.
+        let current = usergroup(&["U0000000A", "U0000000B"], 1700000000);
+        let changes = UsergroupChanges::new(&roster.usergroups[1], Some(&current));
+        assert!(!changes.create && changes.enable && !changes.update);
+        assert_eq!(changes.added, vec![&id("U0000000C")]);
+        assert_eq!(changes.removed, vec![&id("U0000000A")]);
+
+        // The name changed.
+        let current = usergroup(&["U0000000B", "U0000000C"], 0);
+        let changes = UsergroupChanges::new(&roster.usergroups[2], Some(&current));
+        assert!(changes.update);
+        assert!(changes.added.is_empty() && changes.removed.is_empty());
+    }
+}
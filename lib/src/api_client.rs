@@ -1,31 +1,61 @@
+use std::{sync::Arc, time::Duration};
+
 use anyhow::{bail, Result};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use serde_json::from_str;
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use reqwest::{
+    header::{
+        HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, LOCATION, RETRY_AFTER,
+    },
+    redirect::Policy,
+    Proxy, StatusCode, Url,
+};
+use serde_json::{from_str, to_string as to_json};
 use serde_qs::to_string;
 use slack_api::{
-    bots::BotsQuery, conversations::ConversationsQuery, request::Request, response::Response,
-    usergroups::UsergroupsQuery, users::UsersQuery,
+    bots::BotsQuery,
+    chat::ChatQuery,
+    conversations::ConversationsQuery,
+    files::{self, File, FileSummary, FilesQuery},
+    id::ChannelId,
+    reactions::ReactionsQuery,
+    request::{Paginated, Request, RequestEncoding},
+    response::{ErrorResponse, Response},
+    ts::Ts,
+    usergroups::UsergroupsQuery,
+    users::UsersQuery,
+};
+
+use crate::{
+    rate_limit::{RateLimiter, RetryPolicy},
+    Paginator, SlackError,
 };
 
-#[derive(Debug)]
+/// The default base URL of the Slack Web API.
+const DEFAULT_BASE_URL: &str = "https://slack.com/api";
+
+/// A Slack API client. Cloning it is cheap, and the clones share the connection pool and the rate
+/// limits.
+#[derive(Debug, Clone)]
 pub struct ApiClient {
     endpoint: String,
     client: reqwest::Client,
+    /// The `Authorization` header, which is marked as sensitive to keep it out of debug output.
+    authorization: HeaderValue,
+    retry_policy: RetryPolicy,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl ApiClient {
-    /// Create a new Slack API client.
+    /// Create a new Slack API client with the default configuration. Use
+    /// [`ApiClient::builder`] to customize it.
     pub fn new(token: &str) -> Result<Self> {
-        if token.is_empty() {
-            bail!("Empty Slack API token");
-        }
-        let client = reqwest::Client::builder()
-            .default_headers(HeaderMap::from_iter([
-                (CONTENT_TYPE, HeaderValue::from_static("application/json")),
-                (AUTHORIZATION, HeaderValue::from_str(&format!("Bearer {token}"))?),
-            ]))
-            .build()?;
-        Ok(Self { endpoint: "https://slack.com/api".into(), client })
+        Self::builder(token).build()
+    }
+
+    /// Create a new builder for a Slack API client with the given token.
+    pub fn builder(token: &str) -> ApiClientBuilder {
+        ApiClientBuilder::new(token)
     }
 
     /// https://api.slack.com/methods/users.* API
@@ -44,6 +74,14 @@ impl ApiClient {
         self.request(request).await
     }
 
+    /// https://api.slack.com/methods/chat.* API
+    pub async fn chat<T>(&self, request: &T) -> Result<T::Response>
+    where
+        T: ChatQuery,
+    {
+        self.request(request).await
+    }
+
     /// https://api.slack.com/methods/conversations.* API
     pub async fn conversations<T>(&self, request: &T) -> Result<T::Response>
     where
@@ -52,6 +90,22 @@ impl ApiClient {
         self.request(request).await
     }
 
+    /// https://api.slack.com/methods/files.* API
+    pub async fn files<T>(&self, request: &T) -> Result<T::Response>
+    where
+        T: FilesQuery,
+    {
+        self.request(request).await
+    }
+
+    /// https://api.slack.com/methods/reactions.* API
+    pub async fn reactions<T>(&self, request: &T) -> Result<T::Response>
+    where
+        T: ReactionsQuery,
+    {
+        self.request(request).await
+    }
+
     /// https://api.slack.com/methods/usergroups.* API
     pub async fn usergroups<T>(&self, request: &T) -> Result<T::Response>
     where
@@ -60,30 +114,352 @@ impl ApiClient {
         self.request(request).await
     }
 
-    // Helper method to make a request with query `T`, and deserialize the response into
-    // `T::Response`
-    async fn request<T>(&self, request: &T) -> Result<T::Response>
+    /// Walk through the pages of the paginated request, starting from its current cursor. The
+    /// returned [`Paginator`] yields either the pages or the items in them as a `Stream`.
+    pub fn paginate<'a, T>(&'a self, request: T) -> Paginator<'a, T>
     where
-        T: Request,
+        T: Paginated + 'a,
     {
-        let url = format!("{}/{}?{}", self.endpoint, request.path(), to_string(request)?);
+        Paginator::new(self, request)
+    }
+
+    /// Upload a file with the external upload flow, i.e. get an upload URL with
+    /// `files.getUploadURLExternal`, upload the content to it, and complete the upload with
+    /// `files.completeUploadExternal`. The file is shared to the channel, or the thread if
+    /// `thread_ts` is given, or kept private if `channel_id` is `None`.
+    ///
+    /// See: https://api.slack.com/messaging/files#uploading_files
+    pub async fn upload_file(
+        &self,
+        filename: &str,
+        content: impl Into<Vec<u8>>,
+        channel_id: Option<&ChannelId>,
+        thread_ts: Option<Ts>,
+        initial_comment: Option<&str>,
+    ) -> Result<Vec<File>> {
+        let content = content.into();
+        let response = self
+            .files(&files::GetUploadUrlExternal {
+                filename,
+                length: content.len() as u64,
+                alt_txt: None,
+                snippet_type: None,
+            })
+            .await?;
+        let (Some(upload_url), Some(id)) = (response.upload_url, response.file_id) else {
+            bail!("No upload URL returned for {filename}");
+        };
+
+        self.upload_content(&upload_url, content).await?;
+
+        let response = self
+            .files(&files::CompleteUploadExternal {
+                files: vec![FileSummary { id, title: Some(filename.to_string()) }],
+                channel_id,
+                thread_ts,
+                initial_comment,
+            })
+            .await?;
+        Ok(response.files.unwrap_or_default())
+    }
+
+    /// Upload the raw content of a file to the URL returned by `files.getUploadURLExternal`, which
+    /// is pre-signed and doesn't need the token.
+    pub async fn upload_content(
+        &self,
+        upload_url: &str,
+        content: impl Into<Vec<u8>>,
+    ) -> Result<()> {
         let response = self
             .client
-            .request(request.method().into(), &url)
+            .post(upload_url)
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(content.into())
             .send()
-            .await?
-            .text()
             .await?;
+        if !response.status().is_success() {
+            return Err(SlackError::from_status(response.status()).into());
+        }
+        Ok(())
+    }
+
+    /// Download a file from its `url_private` or `url_private_download`, which requires the token.
+    /// The content is streamed as chunks, so that a large file doesn't have to fit in memory.
+    ///
+    /// The token is sent only to a Slack file host over HTTPS, e.g. `files.slack.com`, and a
+    /// redirect to another host is not followed, so that the token doesn't leak to a third party.
+    pub async fn download_file(&self, url: &str) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let url = Url::parse(url)?;
+        let mut builder = self.client.get(url.clone());
+        if is_slack_file_host(&url) {
+            builder = builder.header(AUTHORIZATION, &self.authorization);
+        }
+        let response = builder.send().await?;
+        if response.status().is_redirection() {
+            let location = response.headers().get(LOCATION).and_then(|v| v.to_str().ok());
+            bail!("Refused to follow the redirect of {url} to {}", location.unwrap_or("nowhere"));
+        }
+        if !response.status().is_success() {
+            return Err(SlackError::from_status(response.status()).into());
+        }
+        Ok(response.bytes_stream().map_err(anyhow::Error::from))
+    }
+
+    // Helper method to make a request with query `T`, and deserialize the response into
+    // `T::Response`. A failed request results in a `SlackError`. A rate limited request is retried
+    // according to the retry policy.
+    pub(crate) async fn request<T>(&self, request: &T) -> Result<T::Response>
+    where
+        T: Request,
+    {
+        let mut url = format!("{}/{}", self.endpoint, request.path());
+        let body = match request.encoding() {
+            RequestEncoding::Query => {
+                url = format!("{url}?{}", to_string(request)?);
+                None
+            }
+            RequestEncoding::Form => {
+                Some(("application/x-www-form-urlencoded", to_string(request)?))
+            }
+            RequestEncoding::Json => Some(("application/json; charset=utf-8", to_json(request)?)),
+        };
+        let mut retries = 0;
+
+        loop {
+            self.rate_limiter.acquire(request.path()).await;
+
+            let mut builder = self
+                .client
+                .request(request.method().into(), &url)
+                .header(AUTHORIZATION, &self.authorization);
+            if let Some((content_type, body)) = &body {
+                builder = builder.header(CONTENT_TYPE, *content_type).body(body.clone());
+            }
+            let response = builder.send().await?;
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let response = response.text().await?;
+
+            // println!("Request: {} {url}", request.method());
+            // println!("Response: {response}");
+
+            let result = Self::parse::<T::Response>(status, retry_after, &response);
+            match result {
+                Err(e)
+                    if retries < self.retry_policy.max_retries
+                        && e.downcast_ref::<SlackError>()
+                            .is_some_and(SlackError::is_ratelimited) =>
+                {
+                    retries += 1;
+                    self.rate_limiter.defer(
+                        request.path(),
+                        retry_after.unwrap_or(self.retry_policy.default_delay),
+                    );
+                }
+                _ => return result,
+            }
+        }
+    }
 
-        // println!("Request: {} {url}", request.method());
-        // println!("Response: {response}");
+    // Deserialize the response into `R`, or a `SlackError` if the request failed.
+    fn parse<R>(status: StatusCode, retry_after: Option<Duration>, response: &str) -> Result<R>
+    where
+        R: Response,
+    {
+        let mut error = match from_str::<R>(response) {
+            Ok(result) if result.is_ok() => return Ok(result),
+            Ok(result) => SlackError::new(
+                status,
+                result.error().unwrap_or_default(),
+                // The details of e.g. `missing_scope`, which the response types don't have.
+                from_str::<ErrorResponse>(response).ok(),
+            ),
+            Err(_) if !status.is_success() => SlackError::from_status(status),
+            Err(e) => return Err(e.into()),
+        };
+        error.retry_after = retry_after;
+        Err(error.into())
+    }
+}
 
-        let result = from_str::<T::Response>(&response)?;
+/// Whether the URL is of a Slack file host over HTTPS, e.g. `files.slack.com` or
+/// `files.slack-gov.com`, which the token may be sent to.
+fn is_slack_file_host(url: &Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| host.ends_with(".slack.com") || host.ends_with(".slack-gov.com"))
+}
 
-        if result.is_ok() {
-            Ok(result)
+/// A redirect policy which follows up to 10 redirects within the same origin, and stops at a
+/// redirect to another one.
+fn same_origin_redirects() -> Policy {
+    Policy::custom(|attempt| {
+        let previous = attempt.previous();
+        if previous.len() > 10 {
+            attempt.error("too many redirects")
+        } else if previous
+            .last()
+            .is_some_and(|last| last.origin() != attempt.url().origin())
+        {
+            attempt.stop()
         } else {
-            bail!("Request failed: {response}");
+            attempt.follow()
         }
+    })
+}
+
+/// A builder for [`ApiClient`], to point it at a different endpoint, e.g. a local mock server, a
+/// proxy or GovSlack, and to tune the underlying HTTP client.
+pub struct ApiClientBuilder {
+    token: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    user_agent: String,
+    headers: HeaderMap,
+    client: Option<reqwest::Client>,
+    retry_policy: RetryPolicy,
+    tier_pacing: bool,
+}
+
+impl ApiClientBuilder {
+    fn new(token: &str) -> Self {
+        Self {
+            token: token.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            user_agent: concat!("slack_client/", env!("CARGO_PKG_VERSION")).to_string(),
+            headers: HeaderMap::new(),
+            client: None,
+            retry_policy: RetryPolicy::default(),
+            tier_pacing: false,
+        }
+    }
+
+    /// Set the base URL of the API, e.g. `https://slack-gov.com/api`. Defaults to
+    /// `https://slack.com/api`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the timeout of each request, from when the request starts connecting until the response
+    /// body has finished.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for only the connect phase of each request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the proxy to send requests through.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` header. Defaults to `slack_client/<version>`.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Add a header to be sent with every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Use the given HTTP client as is, instead of building a new one. The timeouts, proxy,
+    /// user agent and headers set to this builder are ignored in this case, as they are the
+    /// configuration of the HTTP client. So is the redirect policy, which by default follows a
+    /// redirect to another host after dropping the `Authorization` header.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set the policy to retry rate limited requests. By default, a request is retried up to 3
+    /// times, honoring the `Retry-After` header of the response.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Enable or disable pacing requests in advance, based on the rate limit tier of each method.
+    /// This avoids hitting the rate limit at all when walking through many pages, at the cost of
+    /// throughput. Disabled by default.
+    pub fn tier_pacing(mut self, enabled: bool) -> Self {
+        self.tier_pacing = enabled;
+        self
+    }
+
+    /// Build the Slack API client.
+    pub fn build(self) -> Result<ApiClient> {
+        if self.token.is_empty() {
+            bail!("Empty Slack API token");
+        }
+
+        let mut authorization = HeaderValue::from_str(&format!("Bearer {}", self.token))?;
+        authorization.set_sensitive(true);
+
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder()
+                    .user_agent(self.user_agent)
+                    .default_headers(self.headers)
+                    .redirect(same_origin_redirects());
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(ApiClient {
+            endpoint: self.base_url,
+            client,
+            authorization,
+            retry_policy: self.retry_policy,
+            rate_limiter: Arc::new(RateLimiter::new(self.tier_pacing)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slack_file_hosts() {
+        let is_file_host = |url: &str| is_slack_file_host(&Url::parse(url).unwrap());
+
+        assert!(is_file_host("https://files.slack.com/files-pri/T1-F1/a.png"));
+        assert!(is_file_host("https://files.slack-gov.com/files-pri/T1-F1/a.png"));
+        assert!(is_file_host("https://example.enterprise.slack.com/files/a.png"));
+        assert!(!is_file_host("http://files.slack.com/files-pri/T1-F1/a.png"));
+        assert!(!is_file_host("https://files.slack.com.example.com/a.png"));
+        assert!(!is_file_host("https://notslack.com/a.png"));
+        assert!(!is_file_host("https://example.com/?files.slack.com"));
     }
 }
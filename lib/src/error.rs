@@ -0,0 +1,200 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+use reqwest::StatusCode;
+use slack_api::response::ErrorResponse;
+
+/// An error returned by the Slack API. You can get this out of the `anyhow::Error` returned by the
+/// [`ApiClient`](crate::ApiClient) with `downcast_ref::<SlackError>()`, and match on its `code`.
+///
+/// See: https://api.slack.com/web#evaluating_responses
+#[derive(Debug, Clone)]
+pub struct SlackError {
+    /// The `error` code of the response.
+    pub code: ErrorCode,
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The scopes required by the method, along with `missing_scope` error.
+    pub needed: Option<String>,
+    /// The scopes the token has, along with `missing_scope` error.
+    pub provided: Option<String>,
+    /// Warnings and errors in detail, from `response_metadata.messages`.
+    pub messages: Vec<String>,
+    /// The `Retry-After` header of a rate limited response.
+    pub retry_after: Option<Duration>,
+}
+
+impl SlackError {
+    /// Create a new error from the HTTP status and the `error` code of the failed response. The
+    /// details, which only some errors have, are taken from `details` if any.
+    pub(crate) fn new(status: StatusCode, code: &str, details: Option<ErrorResponse>) -> Self {
+        let (needed, provided, messages) = match details {
+            Some(d) => (d.needed, d.provided, d.response_metadata.map(|m| m.messages)),
+            None => (None, None, None),
+        };
+        Self {
+            code: code.into(),
+            status,
+            needed,
+            provided,
+            messages: messages.unwrap_or_default(),
+            retry_after: None,
+        }
+    }
+
+    /// Create a new error only from the HTTP status, for a response which doesn't have a valid
+    /// body e.g. `429 Too Many Requests` or `503 Service Unavailable`.
+    pub(crate) fn from_status(status: StatusCode) -> Self {
+        let code = match status {
+            StatusCode::TOO_MANY_REQUESTS => ErrorCode::Ratelimited,
+            StatusCode::SERVICE_UNAVAILABLE => ErrorCode::ServiceUnavailable,
+            _ => ErrorCode::Other(format!("http_{}", status.as_u16())),
+        };
+        Self {
+            code,
+            status,
+            needed: None,
+            provided: None,
+            messages: vec![],
+            retry_after: None,
+        }
+    }
+
+    /// Returns `true` if the request is rate limited.
+    pub fn is_ratelimited(&self) -> bool {
+        self.code == ErrorCode::Ratelimited || self.status == StatusCode::TOO_MANY_REQUESTS
+    }
+}
+
+impl Display for SlackError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Slack API error: {} (HTTP {})", self.code, self.status)?;
+        if let Some(needed) = &self.needed {
+            write!(f, ", needed: {needed}")?;
+        }
+        if let Some(provided) = &self.provided {
+            write!(f, ", provided: {provided}")?;
+        }
+        if !self.messages.is_empty() {
+            write!(f, ", messages: {}", self.messages.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SlackError {}
+
+/// Error codes of the Slack API. Codes which are not listed here fall into `Other`.
+///
+/// See: https://api.slack.com/web#errors, and the "Errors" section of each method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorCode {
+    NotAuthed,
+    InvalidAuth,
+    AccountInactive,
+    TokenRevoked,
+    TokenExpired,
+    NoPermission,
+    MissingScope,
+    NotAllowedTokenType,
+    AccessDenied,
+    Ratelimited,
+    ChannelNotFound,
+    NotInChannel,
+    IsArchived,
+    UserNotFound,
+    UsersNotFound,
+    BotNotFound,
+    MessageNotFound,
+    ThreadNotFound,
+    InvalidCursor,
+    InvalidArguments,
+    InvalidArgName,
+    InvalidTsLatest,
+    InvalidTsOldest,
+    RequestTimeout,
+    InternalError,
+    FatalError,
+    ServiceUnavailable,
+    Other(String),
+}
+
+impl From<&str> for ErrorCode {
+    fn from(value: &str) -> Self {
+        match value {
+            "not_authed" => ErrorCode::NotAuthed,
+            "invalid_auth" => ErrorCode::InvalidAuth,
+            "account_inactive" => ErrorCode::AccountInactive,
+            "token_revoked" => ErrorCode::TokenRevoked,
+            "token_expired" => ErrorCode::TokenExpired,
+            "no_permission" => ErrorCode::NoPermission,
+            "missing_scope" => ErrorCode::MissingScope,
+            "not_allowed_token_type" => ErrorCode::NotAllowedTokenType,
+            "access_denied" => ErrorCode::AccessDenied,
+            "ratelimited" => ErrorCode::Ratelimited,
+            "channel_not_found" => ErrorCode::ChannelNotFound,
+            "not_in_channel" => ErrorCode::NotInChannel,
+            "is_archived" => ErrorCode::IsArchived,
+            "user_not_found" => ErrorCode::UserNotFound,
+            "users_not_found" => ErrorCode::UsersNotFound,
+            "bot_not_found" => ErrorCode::BotNotFound,
+            "message_not_found" => ErrorCode::MessageNotFound,
+            "thread_not_found" => ErrorCode::ThreadNotFound,
+            "invalid_cursor" => ErrorCode::InvalidCursor,
+            "invalid_arguments" => ErrorCode::InvalidArguments,
+            "invalid_arg_name" => ErrorCode::InvalidArgName,
+            "invalid_ts_latest" => ErrorCode::InvalidTsLatest,
+            "invalid_ts_oldest" => ErrorCode::InvalidTsOldest,
+            "request_timeout" => ErrorCode::RequestTimeout,
+            "internal_error" => ErrorCode::InternalError,
+            "fatal_error" => ErrorCode::FatalError,
+            "service_unavailable" => ErrorCode::ServiceUnavailable,
+            other => ErrorCode::Other(other.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        match self {
+            ErrorCode::NotAuthed => "not_authed",
+            ErrorCode::InvalidAuth => "invalid_auth",
+            ErrorCode::AccountInactive => "account_inactive",
+            ErrorCode::TokenRevoked => "token_revoked",
+            ErrorCode::TokenExpired => "token_expired",
+            ErrorCode::NoPermission => "no_permission",
+            ErrorCode::MissingScope => "missing_scope",
+            ErrorCode::NotAllowedTokenType => "not_allowed_token_type",
+            ErrorCode::AccessDenied => "access_denied",
+            ErrorCode::Ratelimited => "ratelimited",
+            ErrorCode::ChannelNotFound => "channel_not_found",
+            ErrorCode::NotInChannel => "not_in_channel",
+            ErrorCode::IsArchived => "is_archived",
+            ErrorCode::UserNotFound => "user_not_found",
+            ErrorCode::UsersNotFound => "users_not_found",
+            ErrorCode::BotNotFound => "bot_not_found",
+            ErrorCode::MessageNotFound => "message_not_found",
+            ErrorCode::ThreadNotFound => "thread_not_found",
+            ErrorCode::InvalidCursor => "invalid_cursor",
+            ErrorCode::InvalidArguments => "invalid_arguments",
+            ErrorCode::InvalidArgName => "invalid_arg_name",
+            ErrorCode::InvalidTsLatest => "invalid_ts_latest",
+            ErrorCode::InvalidTsOldest => "invalid_ts_oldest",
+            ErrorCode::RequestTimeout => "request_timeout",
+            ErrorCode::InternalError => "internal_error",
+            ErrorCode::FatalError => "fatal_error",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
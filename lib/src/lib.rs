@@ -1,8 +1,16 @@
 mod api_client;
+mod error;
 pub mod message_retriever;
+mod paginator;
+mod permalink;
+pub mod rate_limit;
 
-pub use api_client::ApiClient;
+pub use api_client::{ApiClient, ApiClientBuilder};
+pub use error::{ErrorCode, SlackError};
+pub use paginator::Paginator;
+pub use permalink::{PermalinkError, SlackPermalink};
 // Re-export the API modules. Looks not a good idea.
 pub use slack_api::{
-    bots, conversations, request, request::Request, response, response::Response, usergroups, users,
+    blocks, bots, chat, conversations, files, id, reactions, request, request::Paginated,
+    request::Request, response, response::Response, ts, ts::Ts, usergroups, users,
 };
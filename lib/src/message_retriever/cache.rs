@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{bail, Result};
+use slack_api::{
+    bots,
+    bots::Bot,
+    conversations,
+    conversations::Channel,
+    id::{BotId, ChannelId, UserId},
+    usergroups,
+    usergroups::Usergroup,
+    users,
+    users::User,
+};
+use tokio::sync::OnceCell;
+
+use crate::ApiClient;
+
+/// Caches of the users, channels, bots and usergroups looked up while resolving messages, which can
+/// be shared by retrievers of a [`RetrieverSession`](super::RetrieverSession). Concurrent lookups
+/// of the same key share one request. Only successful lookups are cached, so that errors are
+/// reported every time.
+#[derive(Debug, Default)]
+pub(crate) struct Cache {
+    users: Cells<UserId, User>,
+    channels: Cells<ChannelId, Channel>,
+    bots: Cells<BotId, Bot>,
+    /// All usergroups, as there is no API to fetch a single usergroup.
+    usergroups: OnceCell<Vec<Usergroup>>,
+}
+
+/// A cell per key, which is initialized by the first lookup of the key.
+type Cells<K, V> = Mutex<HashMap<K, Arc<OnceCell<Option<V>>>>>;
+
+/// Returns the cached value of the key, or fetch it. A lookup while another one of the same key is
+/// in flight waits for it instead of fetching again.
+async fn lookup<K, V, F>(cells: &Cells<K, V>, key: &K, fetch: F) -> Result<Option<V>>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Future<Output = Result<Option<V>>>,
+{
+    // Don't hold the lock across the fetch.
+    let cell = cells.lock().unwrap().entry(key.clone()).or_default().clone();
+    cell.get_or_try_init(|| fetch).await.cloned()
+}
+
+impl Cache {
+    /// Get the user with `users.info`.
+    pub(crate) async fn user(&self, client: &ApiClient, id: &UserId) -> Result<Option<User>> {
+        lookup(&self.users, id, async { Ok(client.users(&users::Info { id }).await?.user) }).await
+    }
+
+    /// Get the channel with `conversations.info`.
+    pub(crate) async fn channel(
+        &self,
+        client: &ApiClient,
+        id: &ChannelId,
+    ) -> Result<Option<Channel>> {
+        lookup(&self.channels, id, async {
+            Ok(client.conversations(&conversations::Info::new(id)).await?.channel)
+        })
+        .await
+    }
+
+    /// Get the bot with `bots.info`.
+    pub(crate) async fn bot(&self, client: &ApiClient, id: &BotId) -> Result<Option<Bot>> {
+        lookup(&self.bots, id, async { Ok(client.bots(&bots::Info { id }).await?.bot) }).await
+    }
+
+    /// Get all usergroups with `usergroups.list`, which is fetched only once even if called
+    /// concurrently.
+    pub(crate) async fn usergroups(&self, client: &ApiClient) -> Result<&[Usergroup]> {
+        let usergroups = self
+            .usergroups
+            .get_or_try_init(|| async {
+                match client
+                    .usergroups(&usergroups::List {
+                        include_count: None,
+                        include_disabled: None,
+                        include_users: None,
+                        usergroup_ids: None,
+                    })
+                    .await?
+                    .usergroups
+                {
+                    Some(list) => Ok(list),
+                    None => bail!("Failed to get usergroups"),
+                }
+            })
+            .await?;
+        Ok(usergroups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use anyhow::anyhow;
+    use tokio::task::yield_now;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn shares_in_flight_lookup() {
+        let cells: Cells<u32, String> = Default::default();
+        let count = AtomicUsize::new(0);
+        let fetch = || async {
+            count.fetch_add(1, Ordering::SeqCst);
+            // Let the other lookups start while this one is in flight.
+            yield_now().await;
+            Ok(Some("value".to_string()))
+        };
+
+        let (a, b, c) = tokio::join!(
+            lookup(&cells, &1, fetch()),
+            lookup(&cells, &1, fetch()),
+            lookup(&cells, &2, fetch())
+        );
+
+        assert_eq!(a.unwrap().as_deref(), Some("value"));
+        assert_eq!(b.unwrap().as_deref(), Some("value"));
+        assert_eq!(c.unwrap().as_deref(), Some("value"));
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        // Cached.
+        lookup(&cells, &1, fetch()).await.unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_cache_errors() {
+        let cells: Cells<u32, String> = Default::default();
+
+        let error = lookup(&cells, &1, async { Err(anyhow!("user_not_found")) }).await;
+        assert!(error.is_err());
+
+        let value = lookup(&cells, &1, async { Ok(Some("value".to_string())) }).await;
+        assert_eq!(value.unwrap().as_deref(), Some("value"));
+    }
+}
@@ -0,0 +1,661 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use slack_api::{
+    blocks::{ListStyle, RichText, RichTextElement, RichTextInline, TextStyle},
+    conversations,
+    id::{ChannelId, UserId},
+    users,
+};
+
+use crate::ApiClient;
+
+static RE_MENTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"@(here|channel|everyone)\b|@([\w\-]+(?:\.[\w\-]+)*)|#([\w\-]+)|:([a-z0-9_+\-]+):")
+        .unwrap()
+});
+
+/// Resolves `@handle` and `#channel` in Markdown to Slack IDs, for [`markdown_to_mrkdwn`] and
+/// [`markdown_to_rich_text`]. Mentions which are not resolved are left as plain text.
+pub trait MentionLookup {
+    /// Returns the ID of the user with the given handle, without the leading `@`.
+    fn user_id(&self, handle: &str) -> Option<UserId>;
+
+    /// Returns the ID of the channel with the given name, without the leading `#`.
+    fn channel_id(&self, name: &str) -> Option<ChannelId>;
+}
+
+/// No lookup at all, i.e. no `@handle` or `#channel` is resolved.
+impl MentionLookup for () {
+    fn user_id(&self, _: &str) -> Option<UserId> {
+        None
+    }
+
+    fn channel_id(&self, _: &str) -> Option<ChannelId> {
+        None
+    }
+}
+
+/// A [`MentionLookup`] backed by maps of users and channels, which can be fetched at once with
+/// [`Mentions::fetch`].
+#[derive(Debug, Clone, Default)]
+pub struct Mentions {
+    /// User IDs keyed by the handle, i.e. both the name and the display name.
+    pub users: HashMap<String, UserId>,
+    /// Channel IDs keyed by the name.
+    pub channels: HashMap<String, ChannelId>,
+}
+
+impl Mentions {
+    /// Fetch all users and public channels of the workspace. Deleted users and archived channels
+    /// are skipped.
+    pub async fn fetch(client: &ApiClient) -> Result<Self> {
+        let mut mentions = Self::default();
+
+        let members: Vec<_> = client
+            .paginate(users::List { cursor: None, limit: Some(200) })
+            .items()
+            .try_collect()
+            .await?;
+        for user in members.into_iter().filter(|u| !u.deleted) {
+            if !user.profile.display_name.is_empty() {
+                mentions.users.insert(user.profile.display_name, user.id.clone());
+            }
+            mentions.users.insert(user.name, user.id);
+        }
+
+        let channels: Vec<_> = client
+            .paginate(conversations::List {
+                exclude_archived: Some(true),
+                types: None,
+                cursor: None,
+                limit: Some(200),
+            })
+            .items()
+            .try_collect()
+            .await?;
+        for channel in channels {
+            if let Some(name) = channel.name {
+                mentions.channels.insert(name, channel.id);
+            }
+        }
+
+        Ok(mentions)
+    }
+}
+
+impl MentionLookup for Mentions {
+    fn user_id(&self, handle: &str) -> Option<UserId> {
+        self.users.get(handle).cloned()
+    }
+
+    fn channel_id(&self, name: &str) -> Option<ChannelId> {
+        self.channels.get(name).cloned()
+    }
+}
+
+/// A piece of text, split by mentions and emojis.
+enum Token<'t> {
+    Text(&'t str),
+    User(UserId),
+    Channel(ChannelId),
+    Broadcast(&'t str),
+    Emoji(&'t str),
+}
+
+/// Split the text into tokens. A mention must be at the start of the text or after a whitespace or
+/// an opening bracket, so that e-mail addresses or URL fragments are not treated as mentions. An
+/// emoji must not be next to a letter or digit, and its name must not be all digits, so that e.g.
+/// `12:30:45` is left as is.
+fn tokenize<'t>(text: &'t str, lookup: &impl MentionLookup) -> Vec<Token<'t>> {
+    let mut tokens = Vec::new();
+    let mut last = 0;
+    let mut start = 0;
+
+    while let Some(cap) = RE_MENTION.captures_at(text, start) {
+        let m = cap.get(0).unwrap();
+        let before = text[..m.start()].chars().next_back();
+        let is_boundary = before.is_none_or(|c| c.is_whitespace() || "([{".contains(c));
+        let is_emoji_boundary = !before.is_some_and(char::is_alphanumeric)
+            && !text[m.end()..].chars().next().is_some_and(char::is_alphanumeric);
+        let token = match (cap.get(1), cap.get(2), cap.get(3), cap.get(4)) {
+            (Some(range), ..) if is_boundary => Some(Token::Broadcast(range.as_str())),
+            (_, Some(handle), ..) if is_boundary => {
+                lookup.user_id(handle.as_str()).map(Token::User)
+            }
+            (_, _, Some(name), _) if is_boundary => {
+                lookup.channel_id(name.as_str()).map(Token::Channel)
+            }
+            (_, _, _, Some(name))
+                if is_emoji_boundary && !name.as_str().bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                Some(Token::Emoji(name.as_str()))
+            }
+            _ => None,
+        };
+        match token {
+            Some(token) => {
+                if last < m.start() {
+                    tokens.push(Token::Text(&text[last..m.start()]));
+                }
+                tokens.push(token);
+                last = m.end();
+                start = m.end();
+            }
+            // Retry from the next character, as e.g. the closing colon of `12:30:` may open an
+            // emoji.
+            None => start = m.start() + text[m.start()..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if last < text.len() {
+        tokens.push(Token::Text(&text[last..]));
+    }
+    tokens
+}
+
+/// Escape the control characters of mrkdwn.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Convert CommonMark to Slack mrkdwn, which is the inverse of what
+/// [`MessageRetriever::resolve`](super::MessageRetriever::resolve) does. Headings become bold, as
+/// mrkdwn has no headings, and nested lists are indented with spaces.
+///
+/// See: https://api.slack.com/reference/surfaces/formatting
+pub fn markdown_to_mrkdwn(markdown: &str, lookup: &impl MentionLookup) -> String {
+    let mut writer = MrkdwnWriter::default();
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(Tag::Paragraph) => writer.block_start(),
+            Event::End(TagEnd::Paragraph) => writer.newline(),
+            Event::Start(Tag::Heading { .. }) => {
+                writer.block_start();
+                writer.push("*");
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                writer.push("*");
+                writer.newline();
+            }
+            Event::Start(Tag::BlockQuote(_)) => {
+                writer.block_start();
+                writer.quote_depth += 1;
+            }
+            Event::End(TagEnd::BlockQuote(_)) => writer.quote_depth -= 1,
+            Event::Start(Tag::CodeBlock(_)) => {
+                writer.block_start();
+                writer.push("```\n");
+                writer.in_code_block = true;
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                writer.push("```");
+                writer.newline();
+                writer.in_code_block = false;
+            }
+            Event::Start(Tag::List(start)) => {
+                if writer.lists.is_empty() {
+                    writer.block_start();
+                } else if !writer.at_line_start {
+                    writer.newline();
+                }
+                writer.in_item = false;
+                writer.lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                writer.lists.pop();
+            }
+            Event::Start(Tag::Item) => {
+                let indent = "    ".repeat(writer.lists.len().saturating_sub(1));
+                let marker = match writer.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                writer.push(&format!("{indent}{marker}"));
+                writer.in_item = true;
+            }
+            Event::End(TagEnd::Item) => {
+                if !writer.at_line_start {
+                    writer.newline();
+                }
+                writer.in_item = false;
+            }
+            Event::Start(Tag::Emphasis) | Event::End(TagEnd::Emphasis) => writer.push("_"),
+            Event::Start(Tag::Strong) | Event::End(TagEnd::Strong) => writer.push("*"),
+            Event::Start(Tag::Strikethrough) | Event::End(TagEnd::Strikethrough) => {
+                writer.push("~")
+            }
+            Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                writer.links.push((dest_url.to_string(), String::new()));
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                if let Some((url, text)) = writer.links.pop() {
+                    if text.is_empty() || text == url {
+                        writer.push(&format!("<{url}>"));
+                    } else {
+                        writer.push(&format!("<{url}|{text}>"));
+                    }
+                }
+            }
+            Event::Text(text) if writer.in_code_block => {
+                for line in text.split_inclusive('\n') {
+                    writer.push(&escape(line));
+                }
+            }
+            Event::Text(text) if !writer.links.is_empty() => writer.push(&escape(&text)),
+            Event::Text(text) => {
+                for token in tokenize(&text, lookup) {
+                    match token {
+                        Token::Text(s) => writer.push(&escape(s)),
+                        Token::User(id) => writer.push(&format!("<@{id}>")),
+                        Token::Channel(id) => writer.push(&format!("<#{id}>")),
+                        Token::Broadcast(range) => writer.push(&format!("<!{range}>")),
+                        Token::Emoji(name) => writer.push(&format!(":{name}:")),
+                    }
+                }
+            }
+            Event::Code(code) => writer.push(&format!("`{}`", escape(&code))),
+            Event::Html(html) | Event::InlineHtml(html) => writer.push(&escape(&html)),
+            Event::SoftBreak | Event::HardBreak => writer.newline(),
+            Event::Rule => {
+                writer.block_start();
+                writer.push("---");
+                writer.newline();
+            }
+            _ => {}
+        }
+    }
+
+    writer.output.trim_end().to_string()
+}
+
+/// A writer of mrkdwn, which keeps track of the nesting of blocks.
+#[derive(Default)]
+struct MrkdwnWriter {
+    output: String,
+    /// Whether the next push starts a new line, to prefix it with `> ` in quotes.
+    at_line_start: bool,
+    quote_depth: usize,
+    in_code_block: bool,
+    /// Whether a paragraph is the first one in a list item, which shouldn't be on a new line.
+    in_item: bool,
+    /// The next number of each nested list, or `None` for bullet lists.
+    lists: Vec<Option<u64>>,
+    /// The URL and text of links being written, as mrkdwn puts the URL first.
+    links: Vec<(String, String)>,
+}
+
+impl MrkdwnWriter {
+    fn push(&mut self, s: &str) {
+        if let Some((_, text)) = self.links.last_mut() {
+            text.push_str(s);
+            return;
+        }
+        if self.output.is_empty() || self.at_line_start {
+            self.output.push_str(&"> ".repeat(self.quote_depth));
+        }
+        self.output.push_str(s);
+        self.at_line_start = s.ends_with('\n');
+    }
+
+    fn newline(&mut self) {
+        if let Some((_, text)) = self.links.last_mut() {
+            text.push(' ');
+            return;
+        }
+        // Keep a blank line in a quote quoted.
+        if self.at_line_start && self.quote_depth > 0 {
+            self.output.push_str(&">".repeat(self.quote_depth));
+        }
+        self.output.push('\n');
+        self.at_line_start = true;
+    }
+
+    /// Start a block, separating it from the previous one with a blank line unless in a list.
+    fn block_start(&mut self) {
+        if self.in_item {
+            self.in_item = false;
+            return;
+        }
+        if !self.output.is_empty() && self.lists.is_empty() && !self.output.ends_with("\n\n") {
+            self.newline();
+        }
+    }
+}
+
+/// Convert CommonMark to a `rich_text` block, which is what the Slack client produces for messages
+/// typed by users. Paragraphs and headings are put into sections, separated by newlines, and nested
+/// lists are flattened into lists with `indent`. An ordered list split by a nested list continues
+/// its numbering with `offset`.
+///
+/// See: https://api.slack.com/reference/block-kit/blocks#rich_text
+pub fn markdown_to_rich_text(markdown: &str, lookup: &impl MentionLookup) -> RichText {
+    let mut builder = RichTextBuilder::default();
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(Tag::Paragraph) => builder.paragraph_start(),
+            Event::Start(Tag::Heading { .. }) => {
+                builder.paragraph_start();
+                builder.style.bold += 1;
+            }
+            Event::End(TagEnd::Heading(_)) => builder.style.bold -= 1,
+            Event::Start(Tag::BlockQuote(_)) => builder.flush(),
+            Event::End(TagEnd::BlockQuote(_)) => {
+                let elements = builder.take_inlines();
+                if !elements.is_empty() {
+                    builder.elements.push(RichTextElement::quote(elements));
+                }
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                builder.flush();
+                builder.in_code_block = true;
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                let mut elements = builder.take_inlines();
+                if let Some(RichTextInline::Text { text, .. }) = elements.last_mut() {
+                    while text.ends_with('\n') {
+                        text.pop();
+                    }
+                }
+                builder.elements.push(RichTextElement::preformatted(elements));
+                builder.in_code_block = false;
+            }
+            Event::Start(Tag::List(start)) => {
+                builder.flush_item();
+                builder.flush_list();
+                if builder.lists.is_empty() {
+                    builder.flush();
+                }
+                let (style, offset) = match start {
+                    Some(start) => (ListStyle::Ordered, start.saturating_sub(1)),
+                    None => (ListStyle::Bullet, 0),
+                };
+                builder.lists.push(PendingList { style, items: Vec::new(), offset });
+            }
+            Event::End(TagEnd::List(_)) => {
+                builder.flush_list();
+                builder.lists.pop();
+            }
+            Event::Start(Tag::Item) => builder.item_start = true,
+            Event::End(TagEnd::Item) => {
+                builder.flush_item();
+                builder.item_start = false;
+            }
+            Event::Start(Tag::Emphasis) => builder.style.italic += 1,
+            Event::End(TagEnd::Emphasis) => builder.style.italic -= 1,
+            Event::Start(Tag::Strong) => builder.style.bold += 1,
+            Event::End(TagEnd::Strong) => builder.style.bold -= 1,
+            Event::Start(Tag::Strikethrough) => builder.style.strike += 1,
+            Event::End(TagEnd::Strikethrough) => builder.style.strike -= 1,
+            Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                builder.link = Some((dest_url.to_string(), String::new()));
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                if let Some((url, text)) = builder.link.take() {
+                    let text = (!text.is_empty() && text != url).then_some(text);
+                    let style = builder.style.to_style(false);
+                    builder.inlines.push(RichTextInline::Link { url, text, style });
+                }
+            }
+            Event::Text(text) if builder.in_code_block => builder.push_text(&text, false),
+            Event::Text(text) if builder.link.is_some() => builder.push_text(&text, false),
+            Event::Text(text) => {
+                for token in tokenize(&text, lookup) {
+                    match token {
+                        Token::Text(s) => builder.push_text(s, false),
+                        Token::User(id) => builder.inlines.push(RichTextInline::user(id)),
+                        Token::Channel(id) => builder.inlines.push(RichTextInline::channel(id)),
+                        Token::Broadcast(range) => {
+                            builder.inlines.push(RichTextInline::broadcast(range))
+                        }
+                        Token::Emoji(name) => builder.inlines.push(RichTextInline::emoji(name)),
+                    }
+                }
+            }
+            Event::Code(code) => builder.push_text(&code, true),
+            Event::Html(html) | Event::InlineHtml(html) => builder.push_text(&html, false),
+            Event::SoftBreak | Event::HardBreak => builder.push_text("\n", false),
+            _ => {}
+        }
+    }
+
+    builder.flush();
+    RichText { block_id: None, elements: builder.elements }
+}
+
+/// The nesting level of each style, as they can be nested, e.g. `**bold _and italic_**`.
+#[derive(Default)]
+struct StyleDepth {
+    bold: usize,
+    italic: usize,
+    strike: usize,
+}
+
+impl StyleDepth {
+    fn to_style(&self, code: bool) -> Option<TextStyle> {
+        let style = TextStyle {
+            bold: (self.bold > 0).then_some(true),
+            italic: (self.italic > 0).then_some(true),
+            strike: (self.strike > 0).then_some(true),
+            code: code.then_some(true),
+        };
+        (style != TextStyle::default()).then_some(style)
+    }
+}
+
+/// A list being built, which may be flushed in parts around nested lists.
+struct PendingList {
+    style: ListStyle,
+    items: Vec<RichTextElement>,
+    /// The number of items before the next flushed part, including the start number of the list.
+    offset: u64,
+}
+
+/// A builder of rich text elements, which keeps track of the nesting of blocks.
+#[derive(Default)]
+struct RichTextBuilder {
+    elements: Vec<RichTextElement>,
+    /// Inline elements of the current section, quote, code block or list item.
+    inlines: Vec<RichTextInline>,
+    style: StyleDepth,
+    in_code_block: bool,
+    /// Whether the next paragraph is the first one in a list item.
+    item_start: bool,
+    /// Each nested list, whose items are not yet flushed.
+    lists: Vec<PendingList>,
+    /// The URL and text of the link being built.
+    link: Option<(String, String)>,
+}
+
+impl RichTextBuilder {
+    fn push_text(&mut self, text: &str, code: bool) {
+        if let Some((_, link_text)) = self.link.as_mut() {
+            link_text.push_str(text);
+            return;
+        }
+        let style = self.style.to_style(code);
+        // Merge into the previous text if the style is the same, as Slack does.
+        if let Some(RichTextInline::Text { text: prev, style: prev_style }) =
+            self.inlines.last_mut()
+        {
+            if *prev_style == style {
+                prev.push_str(text);
+                return;
+            }
+        }
+        self.inlines
+            .push(RichTextInline::Text { text: text.to_string(), style });
+    }
+
+    /// Start a paragraph, separating it from the previous one with a blank line.
+    fn paragraph_start(&mut self) {
+        if self.item_start {
+            self.item_start = false;
+        } else if !self.inlines.is_empty() {
+            self.push_text("\n\n", false);
+        }
+    }
+
+    fn take_inlines(&mut self) -> Vec<RichTextInline> {
+        std::mem::take(&mut self.inlines)
+    }
+
+    /// Put the current inline elements into a section.
+    fn flush(&mut self) {
+        if !self.inlines.is_empty() {
+            let elements = self.take_inlines();
+            self.elements.push(RichTextElement::section(elements));
+        }
+    }
+
+    /// Put the current inline elements into an item of the innermost list.
+    fn flush_item(&mut self) {
+        if let Some(list) = self.lists.last_mut() {
+            if !self.inlines.is_empty() {
+                list.items
+                    .push(RichTextElement::section(std::mem::take(&mut self.inlines)));
+            }
+        }
+    }
+
+    /// Put the items of the innermost list so far into a list, indented by its depth. An ordered
+    /// list is numbered on from the items flushed before.
+    fn flush_list(&mut self) {
+        let indent = self.lists.len().saturating_sub(1) as u8;
+        if let Some(list) = self.lists.last_mut() {
+            if !list.items.is_empty() {
+                let offset = match list.style {
+                    ListStyle::Ordered => u32::try_from(list.offset).ok().filter(|&o| o > 0),
+                    ListStyle::Bullet => None,
+                };
+                list.offset += list.items.len() as u64;
+                self.elements.push(RichTextElement::List {
+                    style: list.style,
+                    elements: std::mem::take(&mut list.items),
+                    indent: (indent > 0).then_some(indent),
+                    offset,
+                    border: None,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup() -> Mentions {
+        Mentions {
+            users: HashMap::from([("john.doe".to_string(), "U0123ABCD".parse().unwrap())]),
+            channels: HashMap::from([("general".to_string(), "C0123ABCD".parse().unwrap())]),
+        }
+    }
+
+    #[test]
+    fn mentions() {
+        assert_eq!(
+            markdown_to_mrkdwn("Ask @john.doe. in #general, @here", &lookup()),
+            "Ask <@U0123ABCD>. in <#C0123ABCD>, <!here>"
+        );
+        // Not at a boundary, or not resolved.
+        assert_eq!(
+            markdown_to_mrkdwn("mail john@john.doe or @jane", &lookup()),
+            "mail john@john.doe or @jane"
+        );
+    }
+
+    #[test]
+    fn emojis() {
+        assert_eq!(
+            markdown_to_mrkdwn("Done :tada::+1: (:smile:)", &()),
+            "Done :tada::+1: (:smile:)"
+        );
+
+        let rich_text = markdown_to_rich_text("It's 12:30:45 or :30: now:tada:, see :tada:", &());
+        assert_eq!(
+            rich_text.elements,
+            vec![RichTextElement::section(vec![
+                RichTextInline::text("It's 12:30:45 or :30: now:tada:, see "),
+                RichTextInline::emoji("tada"),
+            ])]
+        );
+
+        // The closing colon of a skipped name may open an emoji.
+        let rich_text = markdown_to_rich_text("at :30::tada:", &());
+        assert_eq!(
+            rich_text.elements,
+            vec![RichTextElement::section(vec![
+                RichTextInline::text("at :30:"),
+                RichTextInline::emoji("tada"),
+            ])]
+        );
+        let rich_text = markdown_to_rich_text("at 12:30 :tada:", &());
+        assert_eq!(
+            rich_text.elements,
+            vec![RichTextElement::section(vec![
+                RichTextInline::text("at 12:30 "),
+                RichTextInline::emoji("tada"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn ordered_list_around_nested_list() {
+        let rich_text =
+            markdown_to_rich_text("3. three\n4. four\n   - nested\n5. five\n6. six", &());
+        let item = |text: &str| RichTextElement::section(vec![RichTextInline::text(text)]);
+        assert_eq!(
+            rich_text.elements,
+            vec![
+                RichTextElement::List {
+                    style: ListStyle::Ordered,
+                    elements: vec![item("three"), item("four")],
+                    indent: None,
+                    offset: Some(2),
+                    border: None,
+                },
+                RichTextElement::List {
+                    style: ListStyle::Bullet,
+                    elements: vec![item("nested")],
+                    indent: Some(1),
+                    offset: None,
+                    border: None,
+                },
+                RichTextElement::List {
+                    style: ListStyle::Ordered,
+                    elements: vec![item("five"), item("six")],
+                    indent: None,
+                    offset: Some(4),
+                    border: None,
+                },
+            ]
+        );
+
+        assert_eq!(
+            markdown_to_mrkdwn("3. three\n4. four\n   - nested\n5. five", &()),
+            "3. three\n4. four\n    • nested\n5. five"
+        );
+    }
+
+    #[test]
+    fn ordered_list_from_one() {
+        let rich_text = markdown_to_rich_text("1. one\n   1. nested\n2. two", &());
+        let offsets: Vec<_> = rich_text
+            .elements
+            .iter()
+            .map(|e| match e {
+                RichTextElement::List { indent, offset, .. } => (*indent, *offset),
+                _ => panic!("{e:?}"),
+            })
+            .collect();
+        assert_eq!(offsets, vec![(None, None), (Some(1), None), (None, Some(1))]);
+    }
+}
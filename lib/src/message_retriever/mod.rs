@@ -1,30 +1,88 @@
 use std::{
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
-    sync::LazyLock,
+    sync::{Arc, LazyLock},
 };
 
-use anyhow::{anyhow, bail, Result};
-use regex::Regex;
-use serde::Deserialize;
-use slack_api::{bots, conversations, conversations::Message, usergroups, users, users::User};
+use anyhow::{bail, Result};
+use cache::Cache;
+use futures::{stream, try_join, StreamExt, TryStreamExt};
+use regex::{Captures, Regex};
+use slack_api::{
+    conversations,
+    conversations::{Attachment, Message},
+    files::File,
+    id::{ChannelId, UserId, UsergroupId},
+    ts::Ts,
+    users::User,
+};
 use slack_emojify::Emojify;
-use state::{Initialized, MessageRetrieverState, Resolved, Uninitialized};
+use state::{
+    Initialized, MessageRetrieverState, Resolved, ResolvedMessage, ResolvedReaction, Uninitialized,
+};
 use url::Url;
 
-use crate::ApiClient;
+use crate::{
+    ApiClient,
+    ErrorCode::{self, BotNotFound, ChannelNotFound, NotInChannel, UserNotFound},
+    SlackError, SlackPermalink,
+};
 
+mod cache;
+mod markdown;
+mod render;
+mod session;
 pub mod state;
 
-static RE_CHANNEL: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"<#([CG][A-Z0-9]+)(\|.*)?>").unwrap());
-static RE_USER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<@([UW][A-Z0-9]+)>").unwrap());
-static RE_USERGROUP: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"<!subteam\^([A-Z0-9]+)>").unwrap());
-static RE_SPECIAL_MENTION: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"<!(here|channel|everyone)>").unwrap());
-static RE_LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<([^|]+)\|([^>]+)?>").unwrap());
+pub use markdown::{markdown_to_mrkdwn, markdown_to_rich_text, MentionLookup, Mentions};
+pub use render::{
+    HtmlRenderer, JsonRenderer, MarkdownRenderer, PlainTextRenderer, Renderer, Segment, Style,
+};
+pub use session::RetrieverSession;
+
+/// Code blocks, inline code, channel (`<#CID|label>`), user (`<@UID>`), usergroup
+/// (`<!subteam^ID|label>`) and special (`<!here>`) mentions, images and links in Markdown
+/// (`![alt](url)`, `[label](url)`), links in mrkdwn (`<url|label>`) and emojis (`:name:`).
+static RE_SEGMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?s)```(?P<code_block>.*?)```",
+        r"|`(?P<code>[^`\n]+)`",
+        r"|<#(?P<channel>[CG][A-Z0-9]+)(?:\|(?P<channel_label>[^>]*))?>",
+        r"|<@(?P<user>[UW][A-Z0-9]+)(?:\|[^>]*)?>",
+        r"|<!subteam\^(?P<usergroup>[A-Z0-9]+)(?:\|(?P<usergroup_label>[^>]*))?>",
+        r"|<!(?P<broadcast>here|channel|everyone)(?:\|[^>]*)?>",
+        r"|(?P<image>!)?\[(?P<md_label>[^\]]*)\]\((?P<md_url>[^)\s]+)\)",
+        r"|<(?P<url>[^!#@|>\s][^|>\s]*)(?:\|(?P<url_label>[^>]*))?>",
+        r"|:(?P<emoji>[a-z0-9_+'-]+):",
+    ))
+    .unwrap()
+});
+
+/// The name of a channel or the author of a message which can't be determined, e.g. of a system
+/// message.
+pub const UNKNOWN_NAME: &str = "UNKNOWN";
+
+/// The number of channels, and of users, looked up at the same time to resolve the mentions.
+const LOOKUP_CONCURRENCY: usize = 4;
+
+/// Which messages to retrieve for a link.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Scope {
+    /// Only the linked message.
+    #[default]
+    Single,
+    /// The whole thread the linked message belongs to, starting from the parent.
+    Thread,
+    /// The parent of the thread and up to N replies.
+    ParentAndReplies(usize),
+    /// The messages in the channel after the linked one, excluding it and the replies in threads,
+    /// e.g. the unread messages after the last read one. Up to the latest N messages if given.
+    /// Unlike the other scopes, there may be no messages.
+    After(Option<usize>),
+}
 
-/// TODO: for the moment, this will retrieve only 1 message
+/// Retrieves the message of a link, or the thread of it according to the [`Scope`], and resolves
+/// the channel, the authors and the bodies.
 #[derive(Debug)]
 pub struct MessageRetriever<S>
 where
@@ -61,73 +119,82 @@ impl<'a> MessageRetriever<Uninitialized<'_>> {
     /// - `url` - The URL of the message.
     /// - `token` - The Slack API token.
     pub fn try_new(url: &'a Url, token: &'a str) -> Result<MessageRetriever<Initialized<'a>>> {
-        if !url.domain().unwrap_or_default().ends_with("slack.com") {
-            bail!("Not a Slack URL: {url}");
-        }
+        Self::with_client(url, ApiClient::new(token)?, Arc::default())
+    }
 
-        let (channel_id, ts, ts64, thread_ts64) = Self::parse(url)?;
-        Ok(MessageRetriever {
+    /// Create a new Slack message with the given URL, sharing the client and the caches with
+    /// others.
+    pub(crate) fn with_client(
+        url: &'a Url,
+        client: ApiClient,
+        cache: Arc<Cache>,
+    ) -> Result<MessageRetriever<Initialized<'a>>> {
+        let SlackPermalink { channel, ts, thread_ts, .. } = SlackPermalink::try_from(url)?;
+        let mut retriever = Self::at(channel, ts, client, cache);
+        retriever.url = Some(url);
+        retriever.thread_ts = thread_ts;
+        Ok(retriever)
+    }
+
+    /// Create a new Slack message at the given channel and timestamp without a link, sharing the
+    /// client and the caches with others.
+    pub(crate) fn at(
+        channel_id: ChannelId,
+        ts: Ts,
+        client: ApiClient,
+        cache: Arc<Cache>,
+    ) -> MessageRetriever<Initialized<'a>> {
+        MessageRetriever {
             state: Initialized {
-                url,
+                url: None,
                 channel_id,
                 ts,
-                ts64,
-                thread_ts64,
-                client: ApiClient::new(token)?,
-                usergroups: None,
+                thread_ts: None,
+                client,
+                cache,
+                with_reactions: false,
+                scope: Scope::default(),
             },
-        })
+        }
     }
+}
 
-    /// Parse the given URL and return the channel ID, timestamp, and thread timestamp.
-    ///
-    /// # Arguments
-    ///
-    /// - `url` - The URL to parse.
-    ///
-    /// # Returns
-    ///
-    /// A tuple containing the channel ID (from path segments), timestamp as &str (from another path
-    /// segment), timestamp in f64 (parsed the timestamp as f64), and thread timestamp (from query
-    /// parameters).
-    fn parse(url: &Url) -> Result<(&str, &str, f64, Option<f64>)> {
-        let channel_id = url
-            .path_segments()
-            .ok_or(anyhow!("Failed to get path segments"))?
-            .nth(1)
-            .ok_or(anyhow!("Failed to get the last path segment"))?;
-
-        let ts = url
-            .path_segments()
-            .ok_or(anyhow!("Failed to get path segments"))?
-            .last()
-            .ok_or(anyhow!("Failed to get the last path segment"))?;
-
-        let num = ts.trim_start_matches(|c: char| !c.is_numeric());
-        let (int_part, decimal_part) = num.split_at(num.len() - 6);
-        let ts64 = format!("{int_part}.{decimal_part}").parse::<f64>()?;
-
-        let params: QueryParams =
-            serde_qs::Config::new(5, false).deserialize_str(url.query().unwrap_or(""))?;
-
-        Ok((channel_id, num, ts64, params.thread_ts))
-    }
+/// Names of the channels, users and usergroups mentioned in message bodies.
+#[derive(Debug, Default)]
+struct MentionNames {
+    channels: HashMap<ChannelId, String>,
+    users: HashMap<UserId, String>,
+    usergroups: HashMap<UsergroupId, String>,
 }
 
-// Just for extracting `thread_ts` from the query parameters.
-#[derive(Deserialize, Debug, Clone, Copy)]
-struct QueryParams {
-    thread_ts: Option<f64>,
+/// Returns `true` if the error is a `SlackError` with one of the given codes.
+fn is_slack_error(e: &anyhow::Error, codes: &[ErrorCode]) -> bool {
+    e.downcast_ref::<SlackError>()
+        .is_some_and(|e| codes.contains(&e.code))
 }
 
-impl MessageRetriever<Initialized<'_>> {
+impl<'a> MessageRetriever<Initialized<'a>> {
+    /// Include the reactions to the message, with their names converted to emojis, in the resolved
+    /// message.
+    pub fn with_reactions(mut self, with_reactions: bool) -> Self {
+        self.with_reactions = with_reactions;
+        self
+    }
+
+    /// Set which messages to retrieve. Defaults to [`Scope::Single`].
+    pub fn with_scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
     /// Resolve the channel name, user name, and the body of the message with given Slack API token.
     ///
     /// # Arguments
     ///
     /// - `token` - The Slack API token.
-    /// - `process_body` - Whether to process the body of the message. If `true`, then the following
-    ///   extra post-processing, which will take some time, will be done:
+    /// - `process_body` - Whether to resolve the names of the mentions in the body of the message,
+    ///   which will take some time. The body is split into typed [`Segment`]s in either case, as
+    ///   follows, and the mentions which are not resolved are kept with their IDs:
     ///      1. Detect all sub-strings matching `<(.*?)>`.
     ///      2. Within those sub-strings, format content starting with `#C` as a [channel link](https://api.slack.com/reference/surfaces/formatting#linking-channels).
     ///      3. Format content starting with `@U` or `@W` as a [user mention](https://api.slack.com/reference/surfaces/formatting#mentioning-users).
@@ -140,28 +207,39 @@ impl MessageRetriever<Initialized<'_>> {
     /// # Reference
     ///
     /// [Notes on retrieving formatted messages](https://api.slack.com/reference/surfaces/formatting#retrieving-messages)
-    pub async fn resolve(&mut self, process_body: bool) -> Result<MessageRetriever<Resolved>> {
+    pub async fn resolve(&mut self, process_body: bool) -> Result<MessageRetriever<Resolved<'a>>> {
         let channel_info = self.get_channel_info().await?;
         let messages = self.get_messages().await?;
-        let user_name = self.determine_user_name(&messages).await?;
-        let mut body = self.messages_to_body(&messages);
-
-        if process_body {
-            body = self.replace_channel_ids(&body).await?; // Step 2
-            body = self.replace_user_ids(&body).await?; // Step 3
-            body = self.replace_usergroups_ids(&body).await?; // Step 4
-            body = self.replace_special_mentions(&body)?; // Step 5
-            body = self.replace_links(&body)?; // Step 6
+        let bodies = messages.iter().map(|m| self.message_to_body(m)).collect::<Vec<_>>();
+        let names = if process_body {
+            self.resolve_mentions(&bodies).await? // Step 2-4
+        } else {
+            MentionNames::default()
+        };
+        let mut resolved = Vec::with_capacity(messages.len());
+
+        for (message, body) in messages.iter().zip(bodies) {
+            let user_name = self.determine_user_name(message).await?;
+            let reactions = self.with_reactions.then(|| self.message_to_reactions(message));
+            let segments = Self::body_to_segments(&body, &names); // Step 2-7
+            let mut message = ResolvedMessage {
+                user_name,
+                ts: message.ts,
+                body: String::new(),
+                segments,
+                reactions,
+            };
+            message.body = MarkdownRenderer.body(&message);
+            resolved.push(message);
         }
 
         Ok(MessageRetriever {
             state: Resolved {
                 url: self.url,
                 channel_name: channel_info.0,
-                user_name,
-                body,
-                ts: self.ts.parse::<i64>()?,
+                ts: self.ts,
                 is_private_channel: channel_info.1,
+                messages: resolved,
             },
         })
     }
@@ -175,25 +253,19 @@ impl MessageRetriever<Initialized<'_>> {
     ///   the conversation.
     /// - The name will be the normalized name of the channel otherwise.
     async fn get_channel_info(&self) -> Result<(String, bool)> {
-        let channel = match self
-            .client
-            .conversations(&conversations::Info { channel: self.channel_id })
-            .await?
-            .channel
-        {
+        let channel = match self.cache.channel(&self.client, &self.channel_id).await? {
             Some(channel) => channel,
             None => bail!("Channel not found: {}", self.channel_id),
         };
 
         if channel.is_im.unwrap_or_default() {
-            let user = match self
-                .client
-                .users(&users::Info { id: &channel.user.unwrap_or_default() })
-                .await?
-                .user
-            {
+            let user = match channel.user {
+                Some(ref id) => self.cache.user(&self.client, id).await?,
+                None => None,
+            };
+            let user = match user {
                 Some(user) => user.profile.display_name,
-                None => "UNKNOWN".to_string(),
+                None => UNKNOWN_NAME.to_string(),
             };
             return Ok((format!("DM with {user}"), false));
         }
@@ -201,25 +273,76 @@ impl MessageRetriever<Initialized<'_>> {
         if channel.is_mpim.unwrap_or_default() {
             return match channel.purpose {
                 Some(purpose) => Ok((purpose.value, false)),
-                None => Ok(("UNKNOWN".to_string(), false)),
+                None => Ok((UNKNOWN_NAME.to_string(), false)),
             };
         }
 
         let is_private_channel = channel.is_private.unwrap_or_default();
 
-        Ok((channel.name_normalized.unwrap_or_else(|| "UNKNOWN".to_string()), is_private_channel))
+        Ok((
+            channel.name_normalized.unwrap_or_else(|| UNKNOWN_NAME.to_string()),
+            is_private_channel,
+        ))
     }
 
-    /// Get the messages from the Slack API. If the message didn't send to the main channel, the
-    /// response of the `conversation.history` will be blank. I'm not sure why. Try to fetch using
-    /// `conversation.replies` API instead.
+    /// Get the messages in the scope from the Slack API, in chronological order.
     async fn get_messages(&self) -> Result<Vec<Message>> {
+        let max_items = match self.scope {
+            Scope::Single => return self.get_message().await,
+            Scope::After(max_items) => return self.get_messages_after(max_items).await,
+            Scope::Thread => None,
+            Scope::ParentAndReplies(n) => Some(n.saturating_add(1)),
+        };
+
+        let mut paginator = self.client.paginate(conversations::Replies {
+            channel: &self.channel_id,
+            ts: self.thread_ts.unwrap_or(self.ts),
+            latest: None,
+            oldest: None,
+            limit: Some(200),
+            inclusive: Some(true),
+            cursor: None,
+        });
+        if let Some(max_items) = max_items {
+            paginator = paginator.max_items(max_items);
+        }
+        let messages: Vec<Message> = paginator.items().try_collect().await?;
+
+        if messages.is_empty() {
+            bail!("No messages found")
+        }
+        Ok(messages)
+    }
+
+    /// Get the messages after the linked one from the Slack API, up to the latest `max_items` ones,
+    /// in chronological order.
+    async fn get_messages_after(&self, max_items: Option<usize>) -> Result<Vec<Message>> {
+        let mut paginator = self.client.paginate(conversations::History {
+            channel: &self.channel_id,
+            latest: None,
+            oldest: Some(self.ts),
+            limit: Some(200),
+            inclusive: Some(false),
+            cursor: None,
+        });
+        if let Some(max_items) = max_items {
+            paginator = paginator.max_items(max_items);
+        }
+        let mut messages: Vec<Message> = paginator.items().try_collect().await?;
+        messages.reverse(); // The history is in reverse chronological order.
+        Ok(messages)
+    }
+
+    /// Get the linked message from the Slack API. If the message didn't send to the main channel,
+    /// the response of the `conversation.history` will be blank. I'm not sure why. Try to fetch
+    /// using `conversation.replies` API instead.
+    async fn get_message(&self) -> Result<Vec<Message>> {
         let history = self
             .client
             .conversations(&conversations::History {
-                channel: self.channel_id,
-                latest: Some(self.ts64),
-                oldest: Some(self.ts64),
+                channel: &self.channel_id,
+                latest: Some(self.ts),
+                oldest: Some(self.ts),
                 limit: Some(1),
                 inclusive: Some(true),
                 cursor: None,
@@ -235,12 +358,13 @@ impl MessageRetriever<Initialized<'_>> {
             let messages = self
                 .client
                 .conversations(&conversations::Replies {
-                    channel: self.channel_id,
-                    ts: self.thread_ts64.unwrap_or(self.ts64),
-                    latest: Some(self.ts64),
-                    oldest: Some(self.ts64),
+                    channel: &self.channel_id,
+                    ts: self.thread_ts.unwrap_or(self.ts),
+                    latest: Some(self.ts),
+                    oldest: Some(self.ts),
                     limit: Some(1),
                     inclusive: Some(true),
+                    cursor: None,
                 })
                 .await?
                 .messages;
@@ -255,186 +379,260 @@ impl MessageRetriever<Initialized<'_>> {
         bail!("No messages found")
     }
 
-    /// Determine the user name from the messages. If the message is from a user, then get the user
+    /// Determine the user name of the message. If the message is from a user, then get the user
     /// name from the user ID. If the message is from a bot, then get the bot name from the bot ID.
-    async fn determine_user_name(&self, messages: &[Message]) -> Result<String> {
-        let user_id = &messages.last().unwrap().user;
-        let bot_id = &messages.last().unwrap().bot_id;
-
-        // If user ID is there, use it or die.
-        if let Some(id) = user_id {
-            match self.client.users(&users::Info { id }).await?.user {
-                Some(user) => return Ok(self.get_user_name(user)),
-                None => bail!("User not found: {id:?}"),
+    /// If neither is found, e.g. for a system message, a tombstone of a deleted message or a
+    /// deleted bot, fall back to the name sent with the message, or to [`UNKNOWN_NAME`].
+    async fn determine_user_name(&self, message: &Message) -> Result<String> {
+        if let Some(id) = &message.user {
+            match self.cache.user(&self.client, id).await {
+                Ok(Some(user)) => return Ok(self.get_user_name(user)),
+                Ok(None) => {}
+                Err(e) if is_slack_error(&e, &[UserNotFound]) => {}
+                Err(e) => return Err(e),
             }
         }
 
-        // If bot ID is there, use it or die.
-        if let Some(id) = bot_id {
-            match self.client.bots(&bots::Info { id }).await?.bot {
-                Some(bot) => return Ok(bot.name),
-                None => bail!("Bot not found: {id:?}"),
+        if let Some(id) = &message.bot_id {
+            match self.cache.bot(&self.client, id).await {
+                Ok(Some(bot)) => return Ok(bot.name),
+                Ok(None) => {}
+                Err(e) if is_slack_error(&e, &[BotNotFound]) => {}
+                Err(e) => return Err(e),
             }
         }
 
-        bail!("No user or bot found");
+        Ok(message
+            .bot_profile
+            .as_ref()
+            .and_then(|bot| bot.name.clone())
+            .or_else(|| message.username.clone())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| UNKNOWN_NAME.to_string()))
     }
 
-    /// Convert the messages to the body of the message. If the message contains blocks, then
-    /// convert the blocks to the string. Otherwise, return the text of the message.
-    fn messages_to_body(&self, messages: &[Message]) -> String {
-        messages
-            .iter()
-            .flat_map(|m| match &m.blocks {
-                Some(blocks) => blocks.iter().map(|b| b.to_string()).collect::<Vec<String>>(),
-                None => vec![m.text.clone().unwrap_or_default()],
-            })
-            .last()
-            .unwrap_or("".to_string())
-            .emojify()
-    }
+    /// Convert the message to the body. If the message contains blocks, then convert the blocks to
+    /// the string. Otherwise, return the text of the message. Files and attachments of the message
+    /// follow the body.
+    fn message_to_body(&self, message: &Message) -> String {
+        let body = match &message.blocks {
+            Some(blocks) => blocks
+                .iter()
+                .map(|b| b.to_string())
+                .collect::<Vec<String>>()
+                .join("\n"),
+            None => message.text.clone().unwrap_or_default(),
+        };
 
-    /// Replace the channel (`<#CID>`) to the actual channel name.
-    async fn replace_channel_ids(&self, body: &str) -> Result<String> {
-        let mut new_text = String::with_capacity(body.len());
-        let mut last = 0;
+        let mut parts = vec![body];
+        parts.extend(message.files.iter().map(Self::file_to_markdown));
+        parts.extend(message.attachments.iter().map(Self::attachment_to_markdown));
+        parts.retain(|p| !p.is_empty());
 
-        for cap in RE_CHANNEL.captures_iter(body) {
-            if let Some(m) = cap.get(1) {
-                if let Ok(response) = self
-                    .client
-                    .conversations(&conversations::Info { channel: m.as_str() })
-                    .await
-                {
-                    if let Some(channel) = response.channel {
-                        new_text.push_str(&body[last..m.start().saturating_sub(2)]); // remove the `<#`
-                        new_text.push_str("**#");
-                        new_text.push_str(
-                            &channel.name_normalized.unwrap_or_else(|| "Unknown".to_string()),
-                        );
-                        new_text.push_str("**");
-                        last = m.end().saturating_add(match cap.get(2) {
-                            Some(s) => s.as_str().len() + 1,
-                            None => 1,
-                        }); // remove the `(|.*)?>`
-                    }
-                } else {
-                    println!("Failed to get channel: {}", m.as_str());
-                    new_text.push_str(&body[last..m.start().saturating_sub(2)]); // remove the `<#`
-                    new_text.push_str("**#private channel**");
-                    last = m.end().saturating_add(match cap.get(2) {
-                        Some(s) => s.as_str().len() + 1,
-                        None => 1,
-                    });
-                }
-            }
-        }
-        new_text.push_str(&body[last..]);
-        Ok(new_text)
+        parts.join("\n\n")
     }
 
-    /// Replace the user mentions (`<@ID>`) to the actual user name.
-    async fn replace_user_ids(&self, body: &str) -> Result<String> {
-        let mut new_text = String::with_capacity(body.len());
-        let mut last = 0;
+    /// Render the file as an image embed if it is an image, or as a link otherwise.
+    fn file_to_markdown(file: &File) -> String {
+        let title = file
+            .title
+            .as_deref()
+            .or(file.name.as_deref())
+            .unwrap_or(file.id.as_str());
+        let is_image = file.mimetype.as_deref().is_some_and(|m| m.starts_with("image/"));
+        match (is_image, &file.url_private, &file.permalink) {
+            (true, Some(url), _) => format!("![{title}]({url})"),
+            (_, _, Some(url)) | (_, Some(url), None) => format!("[{title}]({url})"),
+            _ => title.to_string(),
+        }
+    }
 
-        for cap in RE_USER.captures_iter(body) {
-            if let Some(m) = cap.get(1) {
-                if let Ok(response) = self.client.users(&users::Info { id: m.as_str() }).await {
-                    if let Some(user) = response.user {
-                        new_text.push_str(&body[last..m.start().saturating_sub(2)]); // remove the `<@`
-                        new_text.push_str("**@");
-                        new_text.push_str(&self.get_user_name(user));
-                        new_text.push_str("**");
-                        last = m.end().saturating_add(1); // remove the `>`
-                    }
-                }
+    /// Render the attachment as a quote block, falling back to its `fallback` if it has nothing
+    /// else to show.
+    fn attachment_to_markdown(attachment: &Attachment) -> String {
+        let mut lines = vec![];
+        if let Some(author) = &attachment.author_name {
+            lines.push(author.to_string());
+        }
+        match (&attachment.title, &attachment.title_link) {
+            (Some(title), Some(link)) => lines.push(format!("**[{title}]({link})**")),
+            (Some(title), None) => lines.push(format!("**{title}**")),
+            (None, Some(link)) => lines.push(format!("<{link}>")),
+            (None, None) => {}
+        }
+        if let Some(text) = &attachment.text {
+            lines.extend(text.lines().map(str::to_string));
+        }
+        for field in &attachment.fields {
+            match (&field.title, &field.value) {
+                (Some(title), Some(value)) => lines.push(format!("**{title}**: {value}")),
+                (Some(s), None) | (None, Some(s)) => lines.push(s.to_string()),
+                (None, None) => {}
             }
         }
-        new_text.push_str(&body[last..]);
-        Ok(new_text)
+        if lines.is_empty() {
+            lines.extend(attachment.fallback.iter().flat_map(|s| s.lines()).map(str::to_string));
+        }
+
+        lines.iter().map(|l| format!("> {l}")).collect::<Vec<_>>().join("\n")
     }
 
-    /// Replace the usergroup mentions (`<!subteam^ID>`) to the actual usergroup handle.
-    async fn replace_usergroups_ids(&mut self, body: &str) -> Result<String> {
-        let mut new_text = String::with_capacity(body.len());
-        let mut last = 0;
+    /// Collect the reactions to the message.
+    fn message_to_reactions(&self, message: &Message) -> Vec<ResolvedReaction> {
+        message
+            .reactions
+            .iter()
+            .map(|r| ResolvedReaction {
+                name: r.name.clone(),
+                emoji: format!(":{}:", r.name).emojify(),
+                count: r.count,
+            })
+            .collect()
+    }
 
-        for cap in RE_USERGROUP.captures_iter(body) {
-            if self.usergroups.as_ref().is_none() {
-                self.usergroups = Some(
-                    match self
-                        .client
-                        .usergroups(&usergroups::List {
-                            include_count: None,
-                            include_disabled: None,
-                            include_users: None,
-                            usergroup_ids: None,
-                        })
-                        .await?
-                        .usergroups
-                    {
-                        Some(list) => list,
-                        None => bail!("Failed to get usergroups"),
-                    },
-                );
+    /// Collect the distinct channels (`<#CID>`), users (`<@UID>`) and usergroups
+    /// (`<!subteam^ID>`) mentioned in the bodies, and resolve their names concurrently, up to
+    /// [`LOOKUP_CONCURRENCY`] channels and users at a time. Each of them is requested only once,
+    /// and the requests are paced by the rate limiter of the client.
+    ///
+    /// - A channel which is not accessible with the token, most likely a private one, is named
+    ///   `private channel`.
+    /// - A user which is not found is left out, so that the mention is left as is.
+    async fn resolve_mentions(&self, bodies: &[String]) -> Result<MentionNames> {
+        let mut channels = HashSet::new();
+        let mut users = HashSet::new();
+        let mut usergroups = HashSet::new();
+
+        // Skip malformed IDs, which are left as text by `body_to_segments` as well.
+        for cap in bodies.iter().flat_map(|body| RE_SEGMENT.captures_iter(body)) {
+            if let Some(m) = cap.name("channel") {
+                channels.extend(m.as_str().parse::<ChannelId>().ok());
+            } else if let Some(m) = cap.name("user") {
+                users.extend(m.as_str().parse::<UserId>().ok());
+            } else if let Some(m) = cap.name("usergroup") {
+                usergroups.extend(m.as_str().parse::<UsergroupId>().ok());
             }
+        }
 
-            if let Some(m) = cap.get(1) {
-                if let Some(list) = self.usergroups.as_ref() {
-                    let group_handle = list.iter().find(|g| g.id == m.as_str());
-                    if let Some(handle) = group_handle {
-                        new_text.push_str(&body[last..m.start().saturating_sub(10)]); // remove the `<subteam^`
-                        new_text.push_str("**@");
-                        new_text.push_str(&handle.handle);
-                        new_text.push_str("**");
-                        last = m.end().saturating_add(1); // remove the `>`
+        let channels = stream::iter(channels)
+            .map(|id| async move {
+                let name = match self.cache.channel(&self.client, &id).await {
+                    Ok(Some(channel)) => {
+                        Some(channel.name_normalized.unwrap_or_else(|| UNKNOWN_NAME.to_string()))
                     }
-                }
+                    Ok(None) => None,
+                    Err(e) if is_slack_error(&e, &[ChannelNotFound, NotInChannel]) => {
+                        Some("private channel".to_string())
+                    }
+                    Err(e) => return Err(e),
+                };
+                Ok(name.map(|name| (id, name)))
+            })
+            .buffer_unordered(LOOKUP_CONCURRENCY)
+            .try_collect::<Vec<_>>();
+        let users = stream::iter(users)
+            .map(|id| async move {
+                let user = match self.cache.user(&self.client, &id).await {
+                    Ok(user) => user,
+                    Err(e) if is_slack_error(&e, &[UserNotFound]) => None,
+                    Err(e) => return Err(e),
+                };
+                Ok(user.map(|user| (id, self.get_user_name(user))))
+            })
+            .buffer_unordered(LOOKUP_CONCURRENCY)
+            .try_collect::<Vec<_>>();
+        let usergroups = async {
+            if usergroups.is_empty() {
+                return Ok(HashMap::new());
             }
-        }
-        new_text.push_str(&body[last..]);
-        Ok(new_text)
-    }
+            let list = self.cache.usergroups(&self.client).await?;
+            Ok::<_, anyhow::Error>(
+                list.iter()
+                    .filter(|g| usergroups.contains(&g.id))
+                    .map(|g| (g.id.clone(), g.handle.clone()))
+                    .collect(),
+            )
+        };
 
-    /// Replace special mentions.
-    fn replace_special_mentions(&self, body: &str) -> Result<String> {
-        let mut new_text = String::with_capacity(body.len());
-        let mut last = 0;
+        let (channels, users, usergroups) = try_join!(channels, users, usergroups)?;
+        Ok(MentionNames {
+            channels: channels.into_iter().flatten().collect(),
+            users: users.into_iter().flatten().collect(),
+            usergroups,
+        })
+    }
 
-        for cap in RE_SPECIAL_MENTION.captures_iter(body) {
-            if let Some(m) = cap.get(1) {
-                new_text.push_str(&body[last..m.start().saturating_sub(2)]); // remove the `<@`
-                new_text.push_str("**@");
-                new_text.push_str(m.as_str());
-                new_text.push_str("**");
-                last = m.end().saturating_add(1); // remove the `>`
+    /// Split the body into typed segments, with the names of the mentions looked up in `names`.
+    /// A channel or usergroup mention which is not resolved falls back to its label, if any.
+    /// Standard emojis are converted to Unicode, Markdown emphasis, quotes and list items are
+    /// structured, and everything else is left as text.
+    fn body_to_segments(body: &str, names: &MentionNames) -> Vec<Segment> {
+        fn push_text(segments: &mut Vec<Segment>, text: &str) {
+            if !text.is_empty() {
+                segments.push(Segment::Text { text: text.to_string() });
             }
         }
-        new_text.push_str(&body[last..]);
-        Ok(new_text)
-    }
-
-    /// Replace the mrkdwn format of the links (`<url|title>`) to the markdown format
-    /// (`[title](url)`). Actually, this is not necessary because the
-    /// `response.conversations#to_string()` will convert the links to the markdown format.
-    fn replace_links(&self, body: &str) -> Result<String> {
-        let mut new_text = String::with_capacity(body.len());
+        let label = |cap: &Captures, name: &str| {
+            cap.name(name)
+                .map(|m| m.as_str())
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+        };
+        let mut segments = vec![];
         let mut last = 0;
 
-        for cap in RE_LINK.captures_iter(body) {
-            if let (Some(url), Some(title)) = (cap.get(1), cap.get(2)) {
-                new_text.push_str(&body[last..url.start().saturating_sub(1)]); // remove the `<`
-                new_text.push('[');
-                new_text.push_str(title.as_str());
-                new_text.push_str(r#"]("#);
-                new_text.push_str(url.as_str());
-                new_text.push(')');
-                last = title.end().saturating_add(1); // remove the `>`
-            }
+        for cap in RE_SEGMENT.captures_iter(body) {
+            let m = cap.get(0).unwrap();
+            let segment = if let Some(code) = cap.name("code_block") {
+                Segment::CodeBlock { code: code.as_str().to_string() }
+            } else if let Some(code) = cap.name("code") {
+                Segment::Code { code: code.as_str().to_string() }
+            } else if let Some(id) = cap.name("channel").and_then(|m| m.as_str().parse().ok()) {
+                let name = names
+                    .channels
+                    .get(&id)
+                    .cloned()
+                    .or_else(|| label(&cap, "channel_label"));
+                Segment::Channel { id, name }
+            } else if let Some(id) = cap.name("user").and_then(|m| m.as_str().parse().ok()) {
+                Segment::User { name: names.users.get(&id).cloned(), id }
+            } else if let Some(id) = cap.name("usergroup").and_then(|m| m.as_str().parse().ok()) {
+                let handle = names
+                    .usergroups
+                    .get(&id)
+                    .cloned()
+                    .or_else(|| label(&cap, "usergroup_label"));
+                Segment::Usergroup { id, handle }
+            } else if let Some(range) = cap.name("broadcast") {
+                Segment::Broadcast { range: range.as_str().to_string() }
+            } else if let Some(url) = cap.name("md_url") {
+                let url = url.as_str().to_string();
+                match cap.name("image") {
+                    Some(_) => Segment::Image { url, alt: cap["md_label"].to_string() },
+                    None => Segment::Link { url, label: label(&cap, "md_label") },
+                }
+            } else if let Some(url) = cap.name("url") {
+                Segment::Link {
+                    url: url.as_str().to_string(),
+                    label: label(&cap, "url_label"),
+                }
+            } else if let Some(name) = cap.name("emoji").map(|m| m.as_str()) {
+                if name.bytes().all(|b| b.is_ascii_digit()) {
+                    continue; // e.g. `12:30:00`, which is not an emoji
+                }
+                let emoji = m.as_str().emojify();
+                let unicode = (emoji != m.as_str()).then_some(emoji);
+                Segment::Emoji { name: name.to_string(), unicode }
+            } else {
+                continue; // a mention with a malformed ID, which is left as text
+            };
+
+            push_text(&mut segments, &body[last..m.start()]);
+            segments.push(segment);
+            last = m.end();
         }
-        new_text.push_str(&body[last..]);
-        Ok(new_text)
+        push_text(&mut segments, &body[last..]);
+        render::structure(segments)
     }
 
     /// Naive implementation to get the username.
@@ -448,3 +646,30 @@ impl MessageRetriever<Initialized<'_>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn skips_malformed_mention_ids() {
+        // Nothing listens on the port, so that any request fails.
+        let client = ApiClient::builder("xoxb-test")
+            .base_url("http://127.0.0.1:9/api")
+            .build()
+            .unwrap();
+        let url = "https://myteam.slack.com/archives/C0123ABCD/p1234567890123456"
+            .parse()
+            .unwrap();
+        let retriever = RetrieverSession::new(client).retriever(&url).unwrap();
+
+        let body = "Hi <!subteam^0BAD|@team> and <!subteam^s0bad>".to_string();
+        let names = retriever.resolve_mentions(std::slice::from_ref(&body)).await.unwrap();
+
+        assert!(names.usergroups.is_empty());
+        assert_eq!(
+            MessageRetriever::body_to_segments(&body, &names),
+            vec![Segment::Text { text: body.clone() }]
+        );
+    }
+}
@@ -0,0 +1,537 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::json;
+use slack_api::{
+    id::{ChannelId, UserId, UsergroupId},
+    ts::Ts,
+};
+use url::Url;
+
+use crate::message_retriever::state::{Resolved, ResolvedMessage};
+
+/// A typed piece of a message body, which a [`Renderer`] turns into the output format.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Segment {
+    /// Plain text.
+    Text { text: String },
+    /// Bold, italic or strikethrough segments.
+    Emphasis { style: Style, segments: Vec<Segment> },
+    /// A quoted line.
+    Quote { segments: Vec<Segment> },
+    /// An item of a list, with the marker, e.g. `-` or `1.`, indented by `indent` spaces.
+    ListItem { indent: usize, marker: String, segments: Vec<Segment> },
+    /// A channel mention, with the name if it is resolved.
+    Channel { id: ChannelId, name: Option<String> },
+    /// A user mention, with the name if it is resolved.
+    User { id: UserId, name: Option<String> },
+    /// A usergroup mention, with the handle if it is resolved.
+    Usergroup { id: UsergroupId, handle: Option<String> },
+    /// A special mention, i.e. `here`, `channel` or `everyone`.
+    Broadcast { range: String },
+    /// A link, with the label if any.
+    Link { url: String, label: Option<String> },
+    /// An embedded image, e.g. an image file shared in the message.
+    Image { url: String, alt: String },
+    /// An emoji, with the Unicode character if it is a standard one.
+    Emoji { name: String, unicode: Option<String> },
+    /// An inline code.
+    Code { code: String },
+    /// A code block.
+    CodeBlock { code: String },
+}
+
+/// The style of [`Segment::Emphasis`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Style {
+    Bold,
+    Italic,
+    Strike,
+}
+
+impl Style {
+    /// The Markdown delimiter of the style.
+    fn delimiter(self) -> &'static str {
+        match self {
+            Style::Bold => "**",
+            Style::Italic => "_",
+            Style::Strike => "~~",
+        }
+    }
+}
+
+/// Markdown emphasis delimiters, i.e. `**bold**`, `_italic_` and `~~strike~~`.
+static RE_EMPHASIS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\*\*|~~|_").unwrap());
+
+/// A Markdown list item, e.g. `- item` or `  1. item`.
+static RE_LIST_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^( *)([-*+•◦▪]|\d+[.)]) ").unwrap());
+
+/// Push the segment, merging a text into the preceding one.
+fn push(segments: &mut Vec<Segment>, segment: Segment) {
+    match (segments.last_mut(), segment) {
+        (_, Segment::Text { text }) if text.is_empty() => {}
+        (Some(Segment::Text { text: last }), Segment::Text { text }) => last.push_str(&text),
+        (_, segment) => segments.push(segment),
+    }
+}
+
+fn push_text(segments: &mut Vec<Segment>, text: &str) {
+    push(segments, Segment::Text { text: text.to_string() });
+}
+
+/// Turn the Markdown emphasis, quotes and list items left in the texts of the segments into
+/// [`Segment::Emphasis`], [`Segment::Quote`] and [`Segment::ListItem`], so that each renderer can
+/// map them to its own format.
+pub(crate) fn structure(segments: Vec<Segment>) -> Vec<Segment> {
+    // Split into lines at the line breaks in the texts.
+    let mut lines = vec![vec![]];
+    for segment in segments {
+        match segment {
+            Segment::Text { text } => {
+                for (i, part) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        lines.push(vec![]);
+                    }
+                    push_text(lines.last_mut().unwrap(), part);
+                }
+            }
+            segment => lines.last_mut().unwrap().push(segment),
+        }
+    }
+
+    let mut structured = vec![];
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            push_text(&mut structured, "\n");
+        }
+        for segment in structure_line(line) {
+            push(&mut structured, segment);
+        }
+    }
+    structured
+}
+
+/// Structure a line, which is a quote or a list item if it starts with `>` or a list marker.
+fn structure_line(mut line: Vec<Segment>) -> Vec<Segment> {
+    let Some(Segment::Text { text }) = line.first_mut() else {
+        return emphasize(line);
+    };
+
+    if let Some(rest) = text.strip_prefix('>') {
+        *text = rest.strip_prefix(' ').unwrap_or(rest).to_string();
+        return vec![Segment::Quote { segments: structure_line(line) }];
+    }
+    if let Some(cap) = RE_LIST_ITEM.captures(text) {
+        let indent = cap[1].len();
+        let marker = cap[2].to_string();
+        *text = text[cap[0].len()..].to_string();
+        return vec![Segment::ListItem { indent, marker, segments: emphasize(line) }];
+    }
+    emphasize(line)
+}
+
+/// Turn the emphasis delimiters in the texts of a line into [`Segment::Emphasis`]. A delimiter
+/// opens if followed by a non-whitespace, and closes if preceded by one. `_` is not a delimiter
+/// within a word, e.g. `snake_case`. Unmatched delimiters are left as text.
+fn emphasize(line: Vec<Segment>) -> Vec<Segment> {
+    // The open emphases with the segments in each, on top of the line itself.
+    let mut stack: Vec<(Option<Style>, Vec<Segment>)> = vec![(None, vec![])];
+
+    for segment in line {
+        let Segment::Text { text } = segment else {
+            stack.last_mut().unwrap().1.push(segment);
+            continue;
+        };
+
+        let mut last = 0;
+        for m in RE_EMPHASIS.find_iter(&text) {
+            let style = match m.as_str() {
+                "**" => Style::Bold,
+                "~~" => Style::Strike,
+                _ => Style::Italic,
+            };
+            let before = text[..m.start()].chars().next_back();
+            let after = text[m.end()..].chars().next();
+            let within_word =
+                |c: Option<char>| style == Style::Italic && c.is_some_and(char::is_alphanumeric);
+            let closes = before.is_some_and(|c| !c.is_whitespace()) && !within_word(after);
+            let opens = after.is_some_and(|c| !c.is_whitespace()) && !within_word(before);
+
+            let open = stack.iter().rposition(|(s, _)| *s == Some(style));
+            if let (true, Some(i)) = (closes, open) {
+                push_text(&mut stack.last_mut().unwrap().1, &text[last..m.start()]);
+                unwind(&mut stack, i + 1);
+                let (_, segments) = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .unwrap()
+                    .1
+                    .push(Segment::Emphasis { style, segments });
+            } else if opens {
+                push_text(&mut stack.last_mut().unwrap().1, &text[last..m.start()]);
+                stack.push((Some(style), vec![]));
+            } else {
+                continue; // left as text
+            }
+            last = m.end();
+        }
+        push_text(&mut stack.last_mut().unwrap().1, &text[last..]);
+    }
+
+    unwind(&mut stack, 1);
+    stack.pop().unwrap().1
+}
+
+/// Put the emphases opened at `depth` or deeper back as text, as they are not closed.
+fn unwind(stack: &mut Vec<(Option<Style>, Vec<Segment>)>, depth: usize) {
+    while stack.len() > depth {
+        let (style, segments) = stack.pop().unwrap();
+        let below = &mut stack.last_mut().unwrap().1;
+        push_text(below, style.map(Style::delimiter).unwrap_or_default());
+        for segment in segments {
+            push(below, segment);
+        }
+    }
+}
+
+/// Renders resolved messages to an output format. Only [`Renderer::segment`] is required, and the
+/// others can be overridden to change how the messages are put together.
+///
+/// ```
+/// use slack_client::message_retriever::{Renderer, Segment};
+///
+/// /// Render only the text, dropping all mentions and links.
+/// struct TextOnly;
+///
+/// impl Renderer for TextOnly {
+///     fn segment(&self, segment: &Segment) -> String {
+///         match segment {
+///             Segment::Text { text } => text.clone(),
+///             _ => String::new(),
+///         }
+///     }
+/// }
+/// ```
+pub trait Renderer {
+    /// Render a segment of a message body.
+    fn segment(&self, segment: &Segment) -> String;
+
+    /// Render a sequence of segments, e.g. the body of a message or the segments in an emphasis.
+    fn segments(&self, segments: &[Segment]) -> String {
+        segments.iter().map(|s| self.segment(s)).collect()
+    }
+
+    /// Render the body of a message.
+    fn body(&self, message: &ResolvedMessage) -> String {
+        self.segments(&message.segments)
+    }
+
+    /// Render a message, which is the body by default.
+    fn message(&self, message: &ResolvedMessage) -> String {
+        self.body(message)
+    }
+
+    /// Render all messages, separated by blank lines by default.
+    fn render(&self, resolved: &Resolved<'_>) -> String {
+        resolved
+            .messages
+            .iter()
+            .map(|m| self.message(m))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Returns the time of the message in RFC 3339, in UTC.
+fn time(ts: &Ts) -> String {
+    match ts.to_timestamp() {
+        Ok(timestamp) => timestamp.to_string(),
+        Err(_) => ts.to_string(),
+    }
+}
+
+/// Renders to Markdown, with mentions in bold. A message is headed by the author and the time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn segment(&self, segment: &Segment) -> String {
+        match segment {
+            Segment::Text { text } => text.clone(),
+            Segment::Emphasis { style, segments } => {
+                let delimiter = style.delimiter();
+                format!("{delimiter}{}{delimiter}", self.segments(segments))
+            }
+            Segment::Quote { segments } => format!("> {}", self.segments(segments)),
+            Segment::ListItem { indent, marker, segments } => {
+                format!("{:indent$}{marker} {}", "", self.segments(segments))
+            }
+            Segment::Channel { name: Some(name), .. } => format!("**#{name}**"),
+            Segment::Channel { id, name: None } => format!("<#{id}>"),
+            Segment::User { name: Some(name), .. } => format!("**@{name}**"),
+            Segment::User { id, name: None } => format!("<@{id}>"),
+            Segment::Usergroup { handle: Some(handle), .. } => format!("**@{handle}**"),
+            Segment::Usergroup { id, handle: None } => format!("<!subteam^{id}>"),
+            Segment::Broadcast { range } => format!("**@{range}**"),
+            Segment::Link { url, label: Some(label) } => format!("[{label}]({url})"),
+            Segment::Link { url, label: None } => format!("<{url}>"),
+            Segment::Image { url, alt } => format!("![{alt}]({url})"),
+            Segment::Emoji { unicode: Some(unicode), .. } => unicode.clone(),
+            Segment::Emoji { name, unicode: None } => format!(":{name}:"),
+            Segment::Code { code } => format!("`{code}`"),
+            Segment::CodeBlock { code } => format!("```\n{}\n```", code.trim_matches('\n')),
+        }
+    }
+
+    fn message(&self, message: &ResolvedMessage) -> String {
+        format!("### {} ({})\n\n{}", message.user_name, time(&message.ts), self.body(message))
+    }
+}
+
+/// Returns the marker of a list item for HTML and plain text, i.e. `•` for a bulleted list, or the
+/// number for a numbered one.
+fn bullet(marker: &str) -> &str {
+    if marker.starts_with(|c: char| c.is_ascii_digit()) {
+        marker
+    } else {
+        "•"
+    }
+}
+
+/// Escape the text for HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders to HTML, e.g. to paste into Confluence. Line breaks in texts become `<br>`, and a
+/// message is wrapped in a `<div>` headed by the author and the time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn segment(&self, segment: &Segment) -> String {
+        match segment {
+            Segment::Text { text } => escape_html(text).replace('\n', "<br>\n"),
+            Segment::Emphasis { style, segments } => {
+                let tag = match style {
+                    Style::Bold => "strong",
+                    Style::Italic => "em",
+                    Style::Strike => "del",
+                };
+                format!("<{tag}>{}</{tag}>", self.segments(segments))
+            }
+            Segment::Quote { segments } => {
+                format!("<blockquote>{}</blockquote>", self.segments(segments))
+            }
+            Segment::ListItem { indent, marker, segments } => {
+                format!(
+                    "{}{} {}",
+                    "&nbsp;".repeat(*indent),
+                    bullet(marker),
+                    self.segments(segments)
+                )
+            }
+            Segment::Channel { id, name } => {
+                format!("<strong>#{}</strong>", escape_html(name.as_deref().unwrap_or(id.as_str())))
+            }
+            Segment::User { id, name } => {
+                format!("<strong>@{}</strong>", escape_html(name.as_deref().unwrap_or(id.as_str())))
+            }
+            Segment::Usergroup { id, handle } => format!(
+                "<strong>@{}</strong>",
+                escape_html(handle.as_deref().unwrap_or(id.as_str()))
+            ),
+            Segment::Broadcast { range } => format!("<strong>@{}</strong>", escape_html(range)),
+            Segment::Link { url, label } => format!(
+                r#"<a href="{}">{}</a>"#,
+                escape_html(url),
+                escape_html(label.as_deref().unwrap_or(url))
+            ),
+            Segment::Image { url, alt } => {
+                format!(r#"<img src="{}" alt="{}">"#, escape_html(url), escape_html(alt))
+            }
+            Segment::Emoji { name, unicode } => match unicode {
+                Some(unicode) => unicode.clone(),
+                None => format!(":{}:", escape_html(name)),
+            },
+            Segment::Code { code } => format!("<code>{}</code>", escape_html(code)),
+            Segment::CodeBlock { code } => {
+                format!("<pre><code>{}</code></pre>", escape_html(code.trim_matches('\n')))
+            }
+        }
+    }
+
+    fn message(&self, message: &ResolvedMessage) -> String {
+        let time = time(&message.ts);
+        format!(
+            "<div class=\"message\">\n<h3>{} <time datetime=\"{time}\">{time}</time></h3>\n<div class=\"body\">{}</div>\n</div>",
+            escape_html(&message.user_name),
+            self.body(message)
+        )
+    }
+}
+
+/// Renders to plain text, e.g. for emails. Mentions are prefixed with `#` or `@`, and links are
+/// followed by the URL in parentheses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainTextRenderer;
+
+impl Renderer for PlainTextRenderer {
+    fn segment(&self, segment: &Segment) -> String {
+        match segment {
+            Segment::Text { text } => text.clone(),
+            Segment::Emphasis { segments, .. } => self.segments(segments),
+            Segment::Quote { segments } => format!("│ {}", self.segments(segments)),
+            Segment::ListItem { indent, marker, segments } => {
+                format!("{:indent$}{} {}", "", bullet(marker), self.segments(segments))
+            }
+            Segment::Channel { id, name } => format!("#{}", name.as_deref().unwrap_or(id.as_str())),
+            Segment::User { id, name } => format!("@{}", name.as_deref().unwrap_or(id.as_str())),
+            Segment::Usergroup { id, handle } => {
+                format!("@{}", handle.as_deref().unwrap_or(id.as_str()))
+            }
+            Segment::Broadcast { range } => format!("@{range}"),
+            Segment::Link { url, label: Some(label) } if label != url => format!("{label} ({url})"),
+            Segment::Link { url, .. } => url.clone(),
+            Segment::Image { url, alt } => format!("{alt} ({url})"),
+            Segment::Emoji { unicode: Some(unicode), .. } => unicode.clone(),
+            Segment::Emoji { name, unicode: None } => format!(":{name}:"),
+            Segment::Code { code } | Segment::CodeBlock { code } => code.clone(),
+        }
+    }
+
+    fn message(&self, message: &ResolvedMessage) -> String {
+        format!("{} ({}):\n{}", message.user_name, time(&message.ts), self.body(message))
+    }
+}
+
+/// Renders to JSON, with the segments as they are, for further processing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonRenderer;
+
+impl JsonRenderer {
+    fn to_value(message: &ResolvedMessage) -> serde_json::Value {
+        json!({
+            "user_name": message.user_name,
+            "ts": message.ts,
+            "segments": message.segments,
+            "reactions": message.reactions,
+        })
+    }
+}
+
+impl Renderer for JsonRenderer {
+    fn segment(&self, segment: &Segment) -> String {
+        serde_json::to_string(segment).unwrap_or_default()
+    }
+
+    fn body(&self, message: &ResolvedMessage) -> String {
+        json!(message.segments).to_string()
+    }
+
+    fn message(&self, message: &ResolvedMessage) -> String {
+        Self::to_value(message).to_string()
+    }
+
+    fn render(&self, resolved: &Resolved<'_>) -> String {
+        json!({
+            "url": resolved.url.map(Url::as_str),
+            "channel_name": resolved.channel_name,
+            "is_private_channel": resolved.is_private_channel,
+            "ts": resolved.ts,
+            "messages": resolved.messages.iter().map(Self::to_value).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Segment {
+        Segment::Text { text: text.to_string() }
+    }
+
+    fn message() -> ResolvedMessage {
+        let segments = structure(vec![
+            text("**Hi** "),
+            Segment::User {
+                id: "U0123ABCD".parse().unwrap(),
+                name: Some("alice".to_string()),
+            },
+            text(", see _this_ & ~~that~~:\n> quoted\n- one\n  1. two\n"),
+            Segment::CodeBlock { code: "\nlet a = 1 < 2;\n".to_string() },
+        ]);
+        ResolvedMessage {
+            user_name: "bob".to_string(),
+            ts: "1700000000.000100".parse().unwrap(),
+            body: String::new(),
+            segments,
+            reactions: None,
+        }
+    }
+
+    #[test]
+    fn structures_segments() {
+        assert_eq!(
+            structure(vec![text("a **b _c_** d\n> e snake_case_name **f")]),
+            vec![
+                text("a "),
+                Segment::Emphasis {
+                    style: Style::Bold,
+                    segments: vec![
+                        text("b "),
+                        Segment::Emphasis { style: Style::Italic, segments: vec![text("c")] },
+                    ],
+                },
+                text(" d\n"),
+                Segment::Quote { segments: vec![text("e snake_case_name **f")] },
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_markdown() {
+        assert_eq!(
+            MarkdownRenderer.message(&message()),
+            "### bob (2023-11-14T22:13:20.0001Z)\n\n**Hi** **@alice**, see _this_ & ~~that~~:\n> quoted\n- one\n  1. two\n```\nlet a = 1 < 2;\n```"
+        );
+    }
+
+    #[test]
+    fn renders_html() {
+        assert_eq!(
+            HtmlRenderer.body(&message()),
+            "<strong>Hi</strong> <strong>@alice</strong>, see <em>this</em> &amp; <del>that</del>:<br>\n<blockquote>quoted</blockquote><br>\n• one<br>\n&nbsp;&nbsp;1. two<br>\n<pre><code>let a = 1 &lt; 2;</code></pre>"
+        );
+    }
+
+    #[test]
+    fn renders_plain_text() {
+        assert_eq!(
+            PlainTextRenderer.body(&message()),
+            "Hi @alice, see this & that:\n│ quoted\n• one\n  1. two\n\nlet a = 1 < 2;\n"
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        let body: serde_json::Value = serde_json::from_str(&JsonRenderer.body(&message())).unwrap();
+        let segments = body.as_array().unwrap();
+
+        assert_eq!(segments.len(), 15);
+        assert_eq!(
+            segments[0],
+            json!({ "type": "emphasis", "style": "bold", "segments": [{ "type": "text", "text": "Hi" }] })
+        );
+        assert_eq!(segments[14], json!({ "type": "code_block", "code": "\nlet a = 1 < 2;\n" }));
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::{stream, StreamExt};
+use slack_api::{id::ChannelId, ts::Ts};
+use url::Url;
+
+use crate::{
+    message_retriever::{
+        cache::Cache,
+        state::{Initialized, Resolved, Uninitialized},
+        MessageRetriever, Scope,
+    },
+    ApiClient,
+};
+
+/// The default number of links resolved at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Resolves many links with one client and shared caches of users, channels, bots and usergroups,
+/// so that each of them is fetched only once in the session.
+///
+/// ```no_run
+/// # async fn run(urls: Vec<url::Url>) -> anyhow::Result<()> {
+/// use slack_client::message_retriever::RetrieverSession;
+///
+/// let session = RetrieverSession::try_new("xoxb-...")?.concurrency(8);
+/// for (url, result) in urls.iter().zip(session.resolve_all(&urls, true).await) {
+///     match result {
+///         Ok(resolved) => println!("{url}\n{}", resolved.transcript()),
+///         Err(e) => eprintln!("{url}: {e}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RetrieverSession {
+    client: ApiClient,
+    cache: Arc<Cache>,
+    concurrency: usize,
+    scope: Scope,
+    with_reactions: bool,
+}
+
+impl RetrieverSession {
+    /// Create a new session with the client.
+    pub fn new(client: ApiClient) -> Self {
+        Self {
+            client,
+            cache: Arc::default(),
+            concurrency: DEFAULT_CONCURRENCY,
+            scope: Scope::default(),
+            with_reactions: false,
+        }
+    }
+
+    /// Create a new session with a client of the default configuration.
+    pub fn try_new(token: &str) -> Result<Self> {
+        Ok(Self::new(ApiClient::new(token)?))
+    }
+
+    /// Set the maximum number of links resolved at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set which messages to retrieve for each link. Defaults to [`Scope::Single`].
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Include the reactions in the resolved messages.
+    pub fn with_reactions(mut self, with_reactions: bool) -> Self {
+        self.with_reactions = with_reactions;
+        self
+    }
+
+    /// Create a retriever for the link, which shares the client and the caches of the session.
+    pub fn retriever<'a>(&self, url: &'a Url) -> Result<MessageRetriever<Initialized<'a>>> {
+        Ok(MessageRetriever::<Uninitialized>::with_client(
+            url,
+            self.client.clone(),
+            self.cache.clone(),
+        )?
+        .with_scope(self.scope)
+        .with_reactions(self.with_reactions))
+    }
+
+    /// Create a retriever for the message at the channel and the timestamp, e.g. the last read one
+    /// of a channel, which shares the client and the caches of the session. The resolved messages
+    /// have no URL.
+    pub fn retriever_at(
+        &self,
+        channel: ChannelId,
+        ts: Ts,
+    ) -> MessageRetriever<Initialized<'static>> {
+        MessageRetriever::<Uninitialized>::at(channel, ts, self.client.clone(), self.cache.clone())
+            .with_scope(self.scope)
+            .with_reactions(self.with_reactions)
+    }
+
+    /// Resolve the links concurrently, up to the concurrency of the session. The results are in the
+    /// same order as the links, and a failure of one doesn't affect the others.
+    pub async fn resolve_all<'a>(
+        &self,
+        urls: &'a [Url],
+        process_body: bool,
+    ) -> Vec<Result<MessageRetriever<Resolved<'a>>>> {
+        stream::iter(urls)
+            .map(|url| async move { self.retriever(url)?.resolve(process_body).await })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
@@ -1,7 +1,13 @@
-use slack_api::usergroups::Usergroup;
+use std::sync::Arc;
+
+use serde::Serialize;
+use slack_api::{id::ChannelId, ts::Ts};
 use url::Url;
 
-use crate::ApiClient;
+use crate::{
+    message_retriever::{cache::Cache, MarkdownRenderer, Renderer, Scope, Segment},
+    ApiClient,
+};
 
 /// A marker trait for the state of a Slack message.
 ///
@@ -11,10 +17,10 @@ use crate::ApiClient;
 /// Possible states are:
 ///
 /// - `Uninitialized`: Just the URL. No content.
-/// - `Initialized`: The message has been initialized with the URL, channel ID, and timestamp. No
-///   content, and the API client is ready.
-/// - `Resolved`: The message has been retrieved and resolved with the channel name, user name, and
-///   message body.
+/// - `Initialized`: The message has been initialized with the channel ID and timestamp, usually
+///   from the URL. No content, and the API client is ready.
+/// - `Resolved`: The messages have been retrieved and resolved with the channel name, user names,
+///   and message bodies.
 pub trait MessageRetrieverState {}
 impl MessageRetrieverState for Uninitialized<'_> {}
 impl MessageRetrieverState for Initialized<'_> {}
@@ -28,35 +34,96 @@ pub struct Uninitialized<'a> {
 
 #[derive(Debug)]
 pub struct Initialized<'a> {
-    /// The plain URL.
-    pub url: &'a Url,
+    /// The plain URL, if the retriever was created from a link.
+    pub url: Option<&'a Url>,
     /// The channel ID.
-    pub channel_id: &'a str,
-    /// The timestamp as &str.
-    pub ts: &'a str,
-    /// The timestamp as f64.
-    pub ts64: f64,
-    /// The thread timestamp as f64.
-    pub thread_ts64: Option<f64>,
+    pub channel_id: ChannelId,
+    /// The timestamp of the message.
+    pub ts: Ts,
+    /// The timestamp of the thread, if the message is in a thread.
+    pub thread_ts: Option<Ts>,
     /// The Slack API client.
     pub client: ApiClient,
-    /// Cache the usergroups to avoid fetching it multiple times, as there is no API to fetch a
-    /// single usergroup.
-    pub(crate) usergroups: Option<Vec<Usergroup>>,
+    /// Cache the users, channels, bots and usergroups to avoid fetching them multiple times.
+    pub(crate) cache: Arc<Cache>,
+    /// Whether to include the reactions in the resolved message.
+    pub(crate) with_reactions: bool,
+    /// Which messages to retrieve.
+    pub(crate) scope: Scope,
 }
 
 #[derive(Debug)]
 pub struct Resolved<'a> {
-    /// The plain URL.
-    pub url: &'a Url,
+    /// The plain URL, if the retriever was created from a link.
+    pub url: Option<&'a Url>,
     /// The channel name.
     pub channel_name: String,
-    /// The user name.
-    pub user_name: String,
-    /// The message body.
-    pub body: String,
-    /// The timestamp as f64.
-    pub ts: i64,
+    /// The timestamp of the linked message.
+    pub ts: Ts,
     /// Is a private channel.
     pub is_private_channel: bool,
+    /// The resolved messages in chronological order, which is only the linked one unless the scope
+    /// is set with `MessageRetriever::with_scope`.
+    pub messages: Vec<ResolvedMessage>,
+}
+
+impl Resolved<'_> {
+    /// Returns the linked message, or the first one if it is not in the retrieved messages.
+    pub fn message(&self) -> Option<&ResolvedMessage> {
+        self.messages
+            .iter()
+            .find(|m| m.ts == self.ts)
+            .or(self.messages.first())
+    }
+
+    /// Returns the user name of the author of the first message, or an empty string if there are
+    /// no messages.
+    #[deprecated(note = "use `message()` or `messages`, as there may be many messages")]
+    pub fn user_name(&self) -> &str {
+        self.messages.first().map_or("", |m| &m.user_name)
+    }
+
+    /// Returns the body of the first message in Markdown, or an empty string if there are no
+    /// messages.
+    #[deprecated(note = "use `message()` or `messages`, as there may be many messages")]
+    pub fn body(&self) -> &str {
+        self.messages.first().map_or("", |m| &m.body)
+    }
+
+    /// Render the messages with the renderer.
+    pub fn render(&self, renderer: &impl Renderer) -> String {
+        renderer.render(self)
+    }
+
+    /// Render the messages as a Markdown transcript, each with a heading of the author and the
+    /// time in UTC.
+    pub fn transcript(&self) -> String {
+        self.render(&MarkdownRenderer)
+    }
+}
+
+/// A resolved message.
+#[derive(Debug, Clone)]
+pub struct ResolvedMessage {
+    /// The user name of the author.
+    pub user_name: String,
+    /// The timestamp of the message.
+    pub ts: Ts,
+    /// The message body, rendered in Markdown.
+    pub body: String,
+    /// The message body as typed segments, to be rendered with a [`Renderer`].
+    pub segments: Vec<Segment>,
+    /// The reactions to the message, if requested with `MessageRetriever::with_reactions`.
+    pub reactions: Option<Vec<ResolvedReaction>>,
+}
+
+/// A reaction to the resolved message.
+#[derive(Serialize, Debug, Clone)]
+pub struct ResolvedReaction {
+    /// The name of the emoji, without colons, e.g. `thumbsup`.
+    pub name: String,
+    /// The emoji converted to Unicode, or `:name:` if it is a custom one.
+    pub emoji: String,
+    /// The number of users who reacted.
+    pub count: u64,
 }
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use slack_api::{request::Paginated, response::Response};
+
+use crate::ApiClient;
+
+/// Walks through the pages of a paginated request, following `response_metadata.next_cursor`
+/// until it is empty. Created by [`ApiClient::paginate`].
+///
+/// See: https://api.slack.com/apis/pagination
+pub struct Paginator<'a, T>
+where
+    T: Paginated,
+{
+    client: &'a ApiClient,
+    request: T,
+    max_items: Option<usize>,
+}
+
+impl<'a, T> Paginator<'a, T>
+where
+    T: Paginated + 'a,
+{
+    pub(crate) fn new(client: &'a ApiClient, request: T) -> Self {
+        Self { client, request, max_items: None }
+    }
+
+    /// Stop fetching pages once the given number of items have been fetched. The `items` stream
+    /// yields exactly up to this number of items, while the `pages` stream yields the whole last
+    /// page.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Returns a stream of the pages i.e. responses.
+    pub fn pages(self) -> impl Stream<Item = Result<T::Response>> + 'a {
+        let Self { client, request, max_items } = self;
+
+        stream::try_unfold((Some(request), 0), move |(request, count)| async move {
+            let Some(mut request) = request else {
+                return Ok(None);
+            };
+            if max_items.is_some_and(|max| count >= max) {
+                return Ok(None);
+            }
+
+            let page = client.request(&request).await?;
+            let count = count + T::items(&page).len();
+            let next = page.next_cursor().map(|cursor| {
+                request.set_cursor(Some(cursor));
+                request
+            });
+            Ok(Some((page, (next, count))))
+        })
+    }
+
+    /// Returns a stream of the items in the pages.
+    pub fn items(self) -> impl Stream<Item = Result<T::Item>> + 'a {
+        let max_items = self.max_items.unwrap_or(usize::MAX);
+
+        self.pages()
+            .map_ok(|page| stream::iter(T::items(&page).to_vec().into_iter().map(Ok)))
+            .try_flatten()
+            .take(max_items)
+    }
+}
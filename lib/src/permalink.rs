@@ -0,0 +1,385 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use slack_api::{
+    id::{ChannelId, ParseIdError, TeamId},
+    ts::{ParseTsError, Ts},
+};
+use url::Url;
+
+/// Domains which Slack workspaces are hosted on, including GovSlack.
+const SLACK_DOMAINS: &[&str] = &["slack.com", "slack-gov.com"];
+
+/// A link to a Slack message. Following forms are supported:
+///
+/// - Archive links: `https://<workspace>.slack.com/archives/C…/p…?thread_ts=…&cid=…`, including Enterprise
+///   Grid hosts `https://<org>.enterprise.slack.com/…`
+/// - Web client links: `https://app.slack.com/client/T…/C…/thread/C…-<ts>`
+/// - Deep links: `slack://channel?team=T…&id=C…&message=<ts>`
+///
+/// See: https://api.slack.com/reference/deep-linking
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlackPermalink {
+    /// The host of the workspace, e.g. `myteam.slack.com`, which only archive links have.
+    pub workspace: Option<String>,
+    /// The team ID, which only web client links and deep links have.
+    pub team: Option<TeamId>,
+    /// The channel ID.
+    pub channel: ChannelId,
+    /// The timestamp of the message.
+    pub ts: Ts,
+    /// The timestamp of the parent message, if the message is in a thread.
+    pub thread_ts: Option<Ts>,
+}
+
+impl SlackPermalink {
+    /// Returns `true` if the given host is of Slack.
+    fn is_slack_host(host: &str) -> bool {
+        SLACK_DOMAINS
+            .iter()
+            .any(|d| host == *d || host.ends_with(&format!(".{d}")))
+    }
+
+    /// Parse an archive link: `/archives/C…/p…?thread_ts=…`.
+    fn parse_archive(url: &Url, host: &str) -> Result<Self, PermalinkError> {
+        let segments = url.path_segments().map(|s| s.collect::<Vec<_>>()).unwrap_or_default();
+        let [_, channel, ts, ..] = segments.as_slice() else {
+            return Err(PermalinkError::MissingTs);
+        };
+
+        Ok(Self {
+            workspace: Some(host.to_string()),
+            team: None,
+            channel: channel.parse()?,
+            ts: Ts::from_permalink(ts)?,
+            thread_ts: Self::query(url, "thread_ts").map(|ts| ts.parse()).transpose()?,
+        })
+    }
+
+    /// Parse a web client link: `/client/T…/C…/thread/C…-<ts>`.
+    fn parse_client(url: &Url) -> Result<Self, PermalinkError> {
+        let segments = url.path_segments().map(|s| s.collect::<Vec<_>>()).unwrap_or_default();
+        match segments.as_slice() {
+            ["client", team, _, "thread", thread, ..] => {
+                let (channel, ts) = thread.split_once('-').ok_or(PermalinkError::MissingTs)?;
+                let ts = ts.parse()?;
+                Ok(Self {
+                    workspace: None,
+                    team: Some(team.parse()?),
+                    channel: channel.parse()?,
+                    ts,
+                    thread_ts: Some(ts),
+                })
+            }
+            ["client", _, _, ..] => Err(PermalinkError::MissingTs),
+            _ => Err(PermalinkError::UnsupportedPath(url.path().to_string())),
+        }
+    }
+
+    /// Parse a deep link: `slack://channel?team=T…&id=C…&message=<ts>`.
+    fn parse_deep_link(url: &Url) -> Result<Self, PermalinkError> {
+        if url.host_str() != Some("channel") {
+            return Err(PermalinkError::UnsupportedPath(url.to_string()));
+        }
+        let ts = Self::query(url, "message").ok_or(PermalinkError::MissingTs)?;
+
+        Ok(Self {
+            workspace: None,
+            team: Self::query(url, "team").map(|team| team.parse()).transpose()?,
+            channel: Self::query(url, "id")
+                .ok_or(PermalinkError::MissingChannel)?
+                .parse()?,
+            // Accept both `1234567890.123456` and `p1234567890123456`.
+            ts: ts.parse().or_else(|_| Ts::from_permalink(&ts))?,
+            thread_ts: Self::query(url, "thread_ts").map(|ts| ts.parse()).transpose()?,
+        })
+    }
+
+    /// Returns the value of the query parameter, if any.
+    fn query(url: &Url, key: &str) -> Option<String> {
+        url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+}
+
+impl TryFrom<&Url> for SlackPermalink {
+    type Error = PermalinkError;
+
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        if url.scheme() == "slack" {
+            return Self::parse_deep_link(url);
+        }
+
+        let host = url.host_str().unwrap_or_default();
+        if !Self::is_slack_host(host) {
+            return Err(PermalinkError::NotSlackUrl(url.to_string()));
+        }
+
+        match url.path_segments().and_then(|mut s| s.next()) {
+            Some("archives") => Self::parse_archive(url, host),
+            Some("client") => Self::parse_client(url),
+            _ => Err(PermalinkError::UnsupportedPath(url.path().to_string())),
+        }
+    }
+}
+
+impl FromStr for SlackPermalink {
+    type Err = PermalinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(&Url::parse(s)?)
+    }
+}
+
+impl Display for SlackPermalink {
+    /// Render as an archive link if the workspace is known, as a web client link for a thread or a
+    /// deep link if the team is known, or as an archive link on `slack.com` otherwise.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Self { workspace, team, channel, ts, thread_ts } = self;
+
+        match (workspace, team, thread_ts) {
+            (None, Some(team), Some(thread_ts)) if thread_ts == ts => write!(
+                f,
+                "https://app.slack.com/client/{team}/{channel}/thread/{channel}-{thread_ts}"
+            ),
+            (None, Some(team), _) => {
+                write!(f, "slack://channel?team={team}&id={channel}&message={ts}")?;
+                match thread_ts {
+                    Some(thread_ts) => write!(f, "&thread_ts={thread_ts}"),
+                    None => Ok(()),
+                }
+            }
+            _ => {
+                let host = workspace.as_deref().unwrap_or("slack.com");
+                write!(f, "https://{host}/archives/{channel}/{}", ts.to_permalink())?;
+                match thread_ts {
+                    Some(thread_ts) if thread_ts != ts => {
+                        write!(f, "?thread_ts={thread_ts}&cid={channel}")
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// An error which can be returned when parsing a Slack permalink.
+#[derive(Debug)]
+pub enum PermalinkError {
+    /// Not a valid URL at all.
+    InvalidUrl(url::ParseError),
+    /// Not a link to Slack.
+    NotSlackUrl(String),
+    /// A link to Slack, but not to a message.
+    UnsupportedPath(String),
+    /// The link doesn't contain the channel ID.
+    MissingChannel,
+    /// The link doesn't contain the timestamp of the message.
+    MissingTs,
+    /// The channel or team ID is malformed.
+    InvalidId(ParseIdError),
+    /// The timestamp is malformed.
+    InvalidTs(ParseTsError),
+}
+
+impl Display for PermalinkError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PermalinkError::InvalidUrl(e) => write!(f, "Invalid URL: {e}"),
+            PermalinkError::NotSlackUrl(url) => write!(f, "Not a Slack URL: {url}"),
+            PermalinkError::UnsupportedPath(path) => {
+                write!(f, "Not a link to a Slack message: {path}")
+            }
+            PermalinkError::MissingChannel => write!(f, "No channel ID in the link"),
+            PermalinkError::MissingTs => write!(f, "No message timestamp in the link"),
+            PermalinkError::InvalidId(e) => write!(f, "{e}"),
+            PermalinkError::InvalidTs(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for PermalinkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PermalinkError::InvalidUrl(e) => Some(e),
+            PermalinkError::InvalidId(e) => Some(e),
+            PermalinkError::InvalidTs(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<url::ParseError> for PermalinkError {
+    fn from(e: url::ParseError) -> Self {
+        PermalinkError::InvalidUrl(e)
+    }
+}
+
+impl From<ParseIdError> for PermalinkError {
+    fn from(e: ParseIdError) -> Self {
+        PermalinkError::InvalidId(e)
+    }
+}
+
+impl From<ParseTsError> for PermalinkError {
+    fn from(e: ParseTsError) -> Self {
+        PermalinkError::InvalidTs(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(s: &str) -> Ts {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_archive_link() {
+        let link: SlackPermalink = "https://myteam.slack.com/archives/C0123ABCD/p1234567890123456"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            link,
+            SlackPermalink {
+                workspace: Some("myteam.slack.com".to_string()),
+                team: None,
+                channel: "C0123ABCD".parse().unwrap(),
+                ts: ts("1234567890.123456"),
+                thread_ts: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_archive_link_in_thread() {
+        let link: SlackPermalink = "https://myorg.enterprise.slack.com/archives/C0123ABCD/p1234567890123456?thread_ts=1234567880.000100&cid=C0123ABCD"
+            .parse()
+            .unwrap();
+        assert_eq!(link.workspace.as_deref(), Some("myorg.enterprise.slack.com"));
+        assert_eq!(link.ts, ts("1234567890.123456"));
+        assert_eq!(link.thread_ts, Some(ts("1234567880.000100")));
+    }
+
+    #[test]
+    fn parses_govslack_link() {
+        let link: SlackPermalink =
+            "https://agency.slack-gov.com/archives/C0123ABCD/p1234567890123456"
+                .parse()
+                .unwrap();
+        assert_eq!(link.workspace.as_deref(), Some("agency.slack-gov.com"));
+        assert_eq!(link.channel.as_str(), "C0123ABCD");
+    }
+
+    #[test]
+    fn parses_client_link() {
+        let link: SlackPermalink =
+            "https://app.slack.com/client/T0123ABCD/C0123ABCD/thread/C0123ABCD-1234567890.123456"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            link,
+            SlackPermalink {
+                workspace: None,
+                team: Some("T0123ABCD".parse().unwrap()),
+                channel: "C0123ABCD".parse().unwrap(),
+                ts: ts("1234567890.123456"),
+                thread_ts: Some(ts("1234567890.123456")),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_deep_link() {
+        let link: SlackPermalink =
+            "slack://channel?team=T0123ABCD&id=C0123ABCD&message=1234567890.123456"
+                .parse()
+                .unwrap();
+        assert_eq!(link.team.as_ref().map(|t| t.as_str()), Some("T0123ABCD"));
+        assert_eq!(link.channel.as_str(), "C0123ABCD");
+        assert_eq!(link.ts, ts("1234567890.123456"));
+        assert_eq!(link.thread_ts, None);
+
+        // The timestamp in the permalink form.
+        let link: SlackPermalink = "slack://channel?id=C0123ABCD&message=p1234567890123456"
+            .parse()
+            .unwrap();
+        assert_eq!(link.team, None);
+        assert_eq!(link.ts, ts("1234567890.123456"));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |s: &str| s.parse::<SlackPermalink>().unwrap_err();
+
+        assert!(matches!(error("not a url"), PermalinkError::InvalidUrl(_)));
+        assert!(matches!(
+            error("https://example.com/archives/C0123ABCD/p1234567890123456"),
+            PermalinkError::NotSlackUrl(_)
+        ));
+        assert!(matches!(
+            error("https://notslack.com/archives/C0123ABCD/p1234567890123456"),
+            PermalinkError::NotSlackUrl(_)
+        ));
+        assert!(matches!(
+            error("https://myteam.slack.com/messages/C0123ABCD"),
+            PermalinkError::UnsupportedPath(_)
+        ));
+        assert!(matches!(
+            error("slack://user?team=T0123ABCD&id=U0123ABCD"),
+            PermalinkError::UnsupportedPath(_)
+        ));
+        assert!(matches!(
+            error("slack://channel?team=T0123ABCD&message=1234567890.123456"),
+            PermalinkError::MissingChannel
+        ));
+        assert!(matches!(
+            error("https://myteam.slack.com/archives/C0123ABCD"),
+            PermalinkError::MissingTs
+        ));
+        assert!(matches!(
+            error("https://app.slack.com/client/T0123ABCD/C0123ABCD"),
+            PermalinkError::MissingTs
+        ));
+        assert!(matches!(
+            error("https://myteam.slack.com/archives/c0123abcd/p1234567890123456"),
+            PermalinkError::InvalidId(_)
+        ));
+        assert!(matches!(
+            error("https://myteam.slack.com/archives/C0123ABCD/p123"),
+            PermalinkError::InvalidTs(_)
+        ));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for s in [
+            "https://myteam.slack.com/archives/C0123ABCD/p1234567890123456",
+            "https://myteam.slack.com/archives/C0123ABCD/p1234567890123456?thread_ts=1234567880.000100&cid=C0123ABCD",
+            "https://app.slack.com/client/T0123ABCD/C0123ABCD/thread/C0123ABCD-1234567890.123456",
+            "slack://channel?team=T0123ABCD&id=C0123ABCD&message=1234567890.123456",
+            "slack://channel?team=T0123ABCD&id=C0123ABCD&message=1234567890.123456&thread_ts=1234567880.000100",
+        ] {
+            let link: SlackPermalink = s.parse().unwrap();
+            assert_eq!(link.to_string(), s);
+            assert_eq!(link.to_string().parse::<SlackPermalink>().unwrap(), link);
+        }
+    }
+
+    #[test]
+    fn displays_without_workspace_or_team() {
+        let link = SlackPermalink {
+            workspace: None,
+            team: None,
+            channel: "C0123ABCD".parse().unwrap(),
+            ts: ts("1234567890.000042"),
+            thread_ts: None,
+        };
+        assert_eq!(link.to_string(), "https://slack.com/archives/C0123ABCD/p1234567890000042");
+        assert_eq!(link.to_string().parse::<SlackPermalink>().unwrap().ts, link.ts);
+    }
+}
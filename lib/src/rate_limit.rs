@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Rate limit tiers of the Slack Web API. Each method belongs to one of them, and the limit is
+/// applied per method per workspace.
+///
+/// See: https://api.slack.com/apis/rate-limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tier {
+    /// 1+ per minute.
+    Tier1,
+    /// 20+ per minute.
+    Tier2,
+    /// 50+ per minute.
+    Tier3,
+    /// 100+ per minute.
+    Tier4,
+    /// Special rate limits, e.g. `chat.postMessage` which allows roughly 1 per second per channel.
+    Special,
+}
+
+impl Tier {
+    /// Returns the tier of the given method, e.g. `users.list`, which is `Request::path()`. Methods
+    /// which are not listed here are considered as Tier 3, the most common one.
+    pub fn of(method: &str) -> Self {
+        match method {
+            "users.list"
+            | "conversations.list"
+            | "conversations.create"
+            | "conversations.archive"
+            | "conversations.unarchive"
+            | "conversations.rename"
+            | "conversations.setTopic"
+            | "conversations.setPurpose"
+            | "conversations.close"
+            | "usergroups.list"
+            | "usergroups.users.list"
+            | "usergroups.create"
+            | "usergroups.update"
+            | "usergroups.enable"
+            | "usergroups.disable"
+            | "usergroups.users.update"
+            | "reactions.remove"
+            | "reactions.list"
+            | "users.setPresence" => Tier::Tier2,
+            "conversations.history"
+            | "conversations.replies"
+            | "conversations.info"
+            | "conversations.invite"
+            | "conversations.kick"
+            | "conversations.join"
+            | "conversations.leave"
+            | "conversations.mark"
+            | "conversations.open"
+            | "users.conversations"
+            | "users.lookupByEmail"
+            | "users.getPresence"
+            | "users.profile.set"
+            | "bots.info"
+            | "reactions.add"
+            | "reactions.get" => Tier::Tier3,
+            "users.info"
+            | "conversations.members"
+            | "users.profile.get"
+            | "chat.postEphemeral"
+            | "files.getUploadURLExternal"
+            | "files.completeUploadExternal"
+            | "files.info" => Tier::Tier4,
+            "chat.postMessage" => Tier::Special,
+            _ => Tier::Tier3,
+        }
+    }
+
+    /// Returns the number of requests allowed per minute.
+    pub fn per_minute(&self) -> u32 {
+        match self {
+            Tier::Tier1 => 1,
+            Tier::Tier2 => 20,
+            Tier::Tier3 => 50,
+            Tier::Tier4 => 100,
+            Tier::Special => 60,
+        }
+    }
+
+    /// Returns the minimum interval between two requests to stay within the tier.
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(60) / self.per_minute()
+    }
+}
+
+/// A policy to retry requests which are rate limited, i.e. HTTP 429 or `ratelimited` error.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// The maximum number of retries for a request. `0` to disable retries.
+    pub max_retries: u32,
+    /// The delay before retrying, used only if the response doesn't have a `Retry-After` header.
+    pub default_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            default_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Default::default() }
+    }
+}
+
+/// Keeps track of the earliest time the next request for each method can be sent.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    /// Whether to pace requests in advance, based on the tier of the method.
+    pacing: bool,
+    /// The earliest time the next request can be sent, per method.
+    next: Mutex<HashMap<&'static str, Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(pacing: bool) -> Self {
+        Self { pacing, next: Mutex::new(HashMap::new()) }
+    }
+
+    /// Wait until a request for the method can be sent, and reserve the slot. Without pacing, this
+    /// waits only for the method deferred by [`RateLimiter::defer`].
+    pub(crate) async fn acquire(&self, method: &'static str) {
+        let wait = {
+            let mut next = self.next.lock().unwrap();
+            let now = Instant::now();
+            let slot = next.get(method).copied().filter(|t| *t > now).unwrap_or(now);
+            if self.pacing {
+                next.insert(method, slot + Tier::of(method).interval());
+            }
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Defer all requests for the method by the given delay, e.g. the `Retry-After` of a rate
+    /// limited response.
+    pub(crate) fn defer(&self, method: &'static str, delay: Duration) {
+        let mut next = self.next.lock().unwrap();
+        let until = Instant::now() + delay;
+        if next.get(method).is_none_or(|t| *t < until) {
+            next.insert(method, until);
+        }
+    }
+}
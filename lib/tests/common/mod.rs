@@ -0,0 +1,176 @@
+//! A stub HTTP server which answers with canned replies and records the requests.
+
+#![allow(dead_code)] // Each test file uses a part of it.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// A request received by the stub server.
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    /// The path and the query, e.g. `/api/users.info?user=U1`.
+    pub target: String,
+    /// The headers, keyed by the lowercase name.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// The API method, e.g. `users.info` of `/api/users.info?user=U1`.
+    pub fn api_method(&self) -> &str {
+        let path = self.target.split('?').next().unwrap_or_default();
+        path.strip_prefix("/api/").unwrap_or(path)
+    }
+
+    /// The query parameters, e.g. `user=U1`, decoded.
+    pub fn query(&self) -> HashMap<String, String> {
+        let query = self.target.split_once('?').map(|(_, q)| q).unwrap_or_default();
+        url::form_urlencoded::parse(query.as_bytes()).into_owned().collect()
+    }
+}
+
+/// A canned HTTP response.
+#[derive(Debug, Clone)]
+pub struct Reply {
+    pub status: &'static str,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+}
+
+impl Reply {
+    /// `200 OK` with the JSON body.
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            status: "200 OK",
+            headers: vec![("Content-Type", "application/json".to_string())],
+            body: body.into(),
+        }
+    }
+
+    /// `200 OK` with `{"ok":true}`.
+    pub fn ok() -> Self {
+        Self::json(r#"{"ok":true}"#)
+    }
+
+    /// The status with an empty body.
+    pub fn status(status: &'static str) -> Self {
+        Self { status, headers: vec![], body: String::new() }
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// A running stub server.
+pub struct Stub {
+    /// The origin, e.g. `http://127.0.0.1:12345`.
+    pub origin: String,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl Stub {
+    /// The base URL of the API, to be passed to `ApiClientBuilder::base_url`.
+    pub fn base_url(&self) -> String {
+        format!("{}/api", self.origin)
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Start a stub server which answers each request with `reply(request, n)`, where `n` is the number
+/// of the requests received before.
+pub async fn serve<F>(reply: F) -> Stub
+where
+    F: Fn(&Recorded, usize) -> Reply + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let origin = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let reply = Arc::new(reply);
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let Some(request) = read_request(&mut socket).await else {
+                continue;
+            };
+            let n = {
+                let mut requests = recorded.lock().unwrap();
+                requests.push(request.clone());
+                requests.len() - 1
+            };
+            let Reply { status, headers, body } = reply(&request, n);
+
+            let mut response = format!("HTTP/1.1 {status}\r\n");
+            for (name, value) in headers {
+                response.push_str(&format!("{name}: {value}\r\n"));
+            }
+            response.push_str(&format!(
+                "Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            ));
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    Stub { origin, requests }
+}
+
+/// Read a request with the body of `Content-Length`.
+async fn read_request(socket: &mut TcpStream) -> Option<Recorded> {
+    let mut data = vec![];
+    let mut buf = [0; 4096];
+    let head_end = loop {
+        if let Some(i) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let read = socket.read(&mut buf).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buf[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&data[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+    let headers: HashMap<_, _> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let length = headers
+        .get("content-length")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(0);
+    while data.len() < head_end + length {
+        let read = socket.read(&mut buf).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        data.extend_from_slice(&buf[..read]);
+    }
+    let body = String::from_utf8_lossy(&data[head_end..]).to_string();
+
+    Some(Recorded { method, target, headers, body })
+}
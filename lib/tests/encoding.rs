@@ -0,0 +1,74 @@
+//! The method, `Content-Type` and body of each request encoding, against a local stub server.
+
+mod common;
+
+use common::{serve, Reply};
+use slack_client::{conversations, files, id::ChannelId, users, ApiClient};
+
+async fn client() -> (ApiClient, common::Stub) {
+    let stub = serve(|_, _| Reply::ok()).await;
+    let client = ApiClient::builder("xoxb-test")
+        .base_url(stub.base_url())
+        .build()
+        .unwrap();
+    (client, stub)
+}
+
+#[tokio::test]
+async fn query() {
+    let (client, stub) = client().await;
+    let id = "U0123ABCD".parse().unwrap();
+
+    client.users(&users::Info { id: &id }).await.unwrap();
+
+    let request = &stub.requests()[0];
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.target, "/api/users.info?user=U0123ABCD");
+    assert_eq!(request.header("content-type"), None);
+    assert_eq!(request.header("authorization"), Some("Bearer xoxb-test"));
+    assert_eq!(request.body, "");
+}
+
+#[tokio::test]
+async fn form() {
+    let (client, stub) = client().await;
+
+    client
+        .files(&files::GetUploadUrlExternal {
+            filename: "a b.txt",
+            length: 3,
+            alt_txt: None,
+            snippet_type: None,
+        })
+        .await
+        .unwrap();
+
+    let request = &stub.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.target, "/api/files.getUploadURLExternal");
+    assert_eq!(request.header("content-type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(request.body, "filename=a+b.txt&length=3");
+}
+
+#[tokio::test]
+async fn json() {
+    let (client, stub) = client().await;
+    let channel: ChannelId = "C0123ABCD".parse().unwrap();
+
+    client
+        .conversations(&conversations::Mark {
+            channel: &channel,
+            ts: "1234567890.000100".parse().unwrap(),
+        })
+        .await
+        .unwrap();
+
+    let request = &stub.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.target, "/api/conversations.mark");
+    assert_eq!(request.header("content-type"), Some("application/json; charset=utf-8"));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&request.body).unwrap(),
+        serde_json::json!({ "channel": "C0123ABCD", "ts": "1234567890.000100" })
+    );
+}
@@ -0,0 +1,92 @@
+//! Uploading and downloading files, against a local stub server.
+
+mod common;
+
+use common::{serve, Reply};
+use futures::TryStreamExt;
+use slack_client::{id::ChannelId, ApiClient};
+
+fn client(base_url: String) -> ApiClient {
+    ApiClient::builder("xoxb-test").base_url(base_url).build().unwrap()
+}
+
+#[tokio::test]
+async fn uploads_file() {
+    let stub = serve(|request, _| match request.target.as_str() {
+        "/api/files.getUploadURLExternal" => Reply::json(format!(
+            r#"{{"ok":true,"upload_url":"http://{}/upload/F1","file_id":"F1"}}"#,
+            request.header("host").unwrap()
+        )),
+        "/upload/F1" => Reply::status("200 OK"),
+        "/api/files.completeUploadExternal" => {
+            Reply::json(r#"{"ok":true,"files":[{"id":"F1","title":"a.txt"}]}"#)
+        }
+        _ => Reply::status("404 Not Found"),
+    })
+    .await;
+    let channel: ChannelId = "C0123ABCD".parse().unwrap();
+
+    let files = client(stub.base_url())
+        .upload_file("a.txt", "abc", Some(&channel), None, Some("hi"))
+        .await
+        .unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].id.to_string(), "F1");
+    assert_eq!(files[0].title.as_deref(), Some("a.txt"));
+
+    let requests = stub.requests();
+    let targets: Vec<_> = requests.iter().map(|r| r.target.as_str()).collect();
+    assert_eq!(
+        targets,
+        ["/api/files.getUploadURLExternal", "/upload/F1", "/api/files.completeUploadExternal"]
+    );
+    assert_eq!(requests[0].body, "filename=a.txt&length=3");
+    // The upload URL is pre-signed, so the token is not sent to it.
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].header("authorization"), None);
+    assert_eq!(requests[1].header("content-type"), Some("application/octet-stream"));
+    assert_eq!(requests[1].body, "abc");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[2].body).unwrap(),
+        serde_json::json!({
+            "files": [{ "id": "F1", "title": "a.txt" }],
+            "channel_id": "C0123ABCD",
+            "initial_comment": "hi",
+        })
+    );
+}
+
+#[tokio::test]
+async fn downloads_file_without_token_from_other_host() {
+    let stub = serve(|_, _| Reply { body: "abc".to_string(), ..Reply::status("200 OK") }).await;
+
+    let chunks: Vec<_> = client(stub.base_url())
+        .download_file(&format!("{}/files-pri/T1-F1/a.txt", stub.origin))
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.concat(), b"abc");
+    assert_eq!(stub.requests()[0].header("authorization"), None);
+}
+
+#[tokio::test]
+async fn does_not_follow_redirect_to_other_host() {
+    let other = serve(|_, _| Reply::ok()).await;
+    let location = format!("{}/a.txt", other.origin);
+    let stub =
+        serve(move |_, _| Reply::status("302 Found").header("Location", location.clone())).await;
+
+    let error = client(stub.base_url())
+        .download_file(&format!("{}/files-pri/T1-F1/a.txt", stub.origin))
+        .await
+        .err()
+        .unwrap();
+
+    assert!(error.to_string().starts_with("Refused to follow the redirect"), "{error}");
+    assert_eq!(stub.requests().len(), 1);
+    assert!(other.requests().is_empty());
+}
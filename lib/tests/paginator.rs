@@ -0,0 +1,167 @@
+//! Walking through pages with a fake paginated request, against a local stub server.
+
+mod common;
+
+use common::{serve, Reply, Stub};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use slack_client::{
+    request::Paginated,
+    response::{Response, ResponseMetadata},
+    ApiClient, Request,
+};
+
+/// A fake paginated request for `numbers.list`, which lists numbers two by two.
+#[derive(Serialize, Debug, Clone)]
+struct List {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct NumbersList {
+    ok: bool,
+    numbers: Vec<u32>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+impl Request for List {
+    type Response = NumbersList;
+
+    fn path(&self) -> &'static str {
+        "numbers.list"
+    }
+}
+
+impl Paginated for List {
+    type Item = u32;
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        &response.numbers
+    }
+}
+
+impl Response for NumbersList {
+    fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn next_cursor(&self) -> Option<String> {
+        self.response_metadata
+            .as_ref()
+            .map(|m| m.next_cursor.clone())
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+/// Serve `[1, 2]`, `[3, 4]` and `[5]`, followed by the cursors `p2`, `p3` and an empty one.
+async fn stub() -> (ApiClient, Stub) {
+    let stub = serve(|request, _| {
+        let (numbers, next_cursor) = match request.query().get("cursor").map(String::as_str) {
+            None => ("[1,2]", "p2"),
+            Some("p2") => ("[3,4]", "p3"),
+            Some("p3") => ("[5]", ""),
+            Some(_) => return Reply::json(r#"{"ok":false,"error":"invalid_cursor"}"#),
+        };
+        Reply::json(format!(
+            r#"{{"ok":true,"numbers":{numbers},"response_metadata":{{"next_cursor":"{next_cursor}"}}}}"#
+        ))
+    })
+    .await;
+    let client = ApiClient::builder("xoxb-test")
+        .base_url(stub.base_url())
+        .build()
+        .unwrap();
+    (client, stub)
+}
+
+/// The cursors sent with the requests.
+fn cursors(stub: &Stub) -> Vec<Option<String>> {
+    stub.requests()
+        .iter()
+        .map(|r| r.query().get("cursor").cloned())
+        .collect()
+}
+
+#[tokio::test]
+async fn follows_cursors_until_empty() {
+    let (client, stub) = stub().await;
+
+    let items: Vec<_> = client
+        .paginate(List { cursor: None })
+        .items()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(items, [1, 2, 3, 4, 5]);
+    assert_eq!(cursors(&stub), [None, Some("p2".to_string()), Some("p3".to_string())]);
+}
+
+#[tokio::test]
+async fn starts_from_cursor_of_request() {
+    let (client, stub) = stub().await;
+
+    let items: Vec<_> = client
+        .paginate(List { cursor: Some("p2".to_string()) })
+        .items()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(items, [3, 4, 5]);
+    assert_eq!(cursors(&stub), [Some("p2".to_string()), Some("p3".to_string())]);
+}
+
+#[tokio::test]
+async fn stops_at_max_items() {
+    let (client, stub) = stub().await;
+
+    let items: Vec<_> = client
+        .paginate(List { cursor: None })
+        .max_items(3)
+        .items()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(stub.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn yields_whole_last_page_up_to_max_items() {
+    let (client, stub) = stub().await;
+
+    let pages: Vec<_> = client
+        .paginate(List { cursor: None })
+        .max_items(3)
+        .pages()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let pages: Vec<_> = pages.into_iter().map(|page| page.numbers).collect();
+    assert_eq!(pages, [vec![1, 2], vec![3, 4]]);
+    assert_eq!(stub.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn stops_at_page_boundary() {
+    let (client, stub) = stub().await;
+
+    let items: Vec<_> = client
+        .paginate(List { cursor: None })
+        .max_items(2)
+        .items()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(items, [1, 2]);
+    assert_eq!(stub.requests().len(), 1);
+}
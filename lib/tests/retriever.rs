@@ -0,0 +1,164 @@
+//! Retrieving messages with the scopes, against a local stub server.
+
+mod common;
+
+use common::{serve, Recorded, Reply};
+use slack_client::{
+    message_retriever::{RetrieverSession, Scope},
+    ApiClient,
+};
+
+const CHANNEL: &str = r#"{"ok":true,"channel":{"id":"C0123ABCD","name":"general","name_normalized":"general","created":0,"is_private":false}}"#;
+
+/// A reply to `users.info` for the user, whose display name is the lowercase ID.
+fn user(request: &Recorded) -> Reply {
+    let id = &request.query()["user"];
+    Reply::json(format!(
+        r#"{{"ok":true,"user":{{"id":"{id}","name":"{name}","is_bot":false,"deleted":false,"team_id":"T0123ABCD","is_app_user":false,"profile":{{"display_name":"{name}","display_name_normalized":"{name}","real_name":"","real_name_normalized":"","title":""}}}}}}"#,
+        name = id.to_lowercase()
+    ))
+}
+
+/// Messages by the users at the timestamps, as a JSON array.
+fn messages(messages: &[(&str, &str)]) -> String {
+    let messages: Vec<_> = messages
+        .iter()
+        .map(|(user, ts)| {
+            format!(r#"{{"type":"message","user":"{user}","text":"hi","ts":"{ts}"}}"#)
+        })
+        .collect();
+    format!("[{}]", messages.join(","))
+}
+
+fn session(stub: &common::Stub) -> RetrieverSession {
+    RetrieverSession::new(
+        ApiClient::builder("xoxb-test")
+            .base_url(stub.base_url())
+            .build()
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn retrieves_messages_after_up_to_max() {
+    let stub = serve(|request, _| match request.api_method() {
+        "conversations.info" => Reply::json(CHANNEL),
+        "conversations.history" => {
+            // In reverse chronological order, two messages per page.
+            let (page, next_cursor) = match request.query().get("cursor").map(String::as_str) {
+                None => (messages(&[("U1", "1700000000.000500"), ("U1", "1700000000.000400")]), "c2"),
+                Some("c2") => (messages(&[("U1", "1700000000.000300"), ("U1", "1700000000.000200")]), "c3"),
+                _ => (messages(&[]), ""),
+            };
+            Reply::json(format!(
+                r#"{{"ok":true,"messages":{page},"has_more":true,"response_metadata":{{"next_cursor":"{next_cursor}"}}}}"#
+            ))
+        }
+        "users.info" => user(request),
+        _ => Reply::status("404 Not Found"),
+    })
+    .await;
+
+    let resolved = session(&stub)
+        .scope(Scope::After(Some(3)))
+        .retriever_at("C0123ABCD".parse().unwrap(), "1700000000.000100".parse().unwrap())
+        .resolve(false)
+        .await
+        .unwrap();
+
+    // The latest three, in chronological order.
+    let ts: Vec<_> = resolved.messages.iter().map(|m| m.ts.to_string()).collect();
+    assert_eq!(ts, ["1700000000.000300", "1700000000.000400", "1700000000.000500"]);
+    assert!(resolved.url.is_none());
+
+    // Stopped at the second page, which has the third message.
+    let history: Vec<_> = stub
+        .requests()
+        .into_iter()
+        .filter(|r| r.api_method() == "conversations.history")
+        .map(|r| r.query())
+        .collect();
+    assert_eq!(history.len(), 2);
+    for query in &history {
+        assert_eq!(query["channel"], "C0123ABCD");
+        assert_eq!(query["oldest"], "1700000000.000100");
+        assert_eq!(query["inclusive"], "false");
+    }
+    assert_eq!(history[1]["cursor"], "c2");
+}
+
+/// A stub which serves a thread of messages by `U1`, `U2`, `U1` and `U3`.
+async fn thread_stub() -> common::Stub {
+    serve(|request, _| match request.api_method() {
+        "conversations.info" => Reply::json(CHANNEL),
+        "conversations.replies" => {
+            let page = messages(&[
+                ("U1", "1700000000.000100"),
+                ("U2", "1700000000.000200"),
+                ("U1", "1700000000.000300"),
+                ("U3", "1700000000.000400"),
+            ]);
+            Reply::json(format!(
+                r#"{{"ok":true,"messages":{page},"has_more":false,"response_metadata":{{"next_cursor":""}}}}"#
+            ))
+        }
+        "users.info" => user(request),
+        _ => Reply::status("404 Not Found"),
+    })
+    .await
+}
+
+const THREAD_URL: &str =
+    "https://myteam.slack.com/archives/C0123ABCD/p1700000000000300?thread_ts=1700000000.000100&cid=C0123ABCD";
+
+#[tokio::test]
+async fn resolves_thread_of_many_authors() {
+    let stub = thread_stub().await;
+    let url = THREAD_URL.parse().unwrap();
+
+    let resolved = session(&stub)
+        .scope(Scope::Thread)
+        .retriever(&url)
+        .unwrap()
+        .resolve(false)
+        .await
+        .unwrap();
+
+    let authors: Vec<_> = resolved.messages.iter().map(|m| m.user_name.as_str()).collect();
+    assert_eq!(authors, ["u1", "u2", "u1", "u3"]);
+    assert_eq!(resolved.channel_name, "general");
+    // The linked message is the reply, while the first one is the parent.
+    assert_eq!(resolved.message().unwrap().ts.to_string(), "1700000000.000300");
+    #[allow(deprecated)]
+    {
+        assert_eq!(resolved.user_name(), "u1");
+        assert_eq!(resolved.body(), "hi");
+    }
+    // Each author is looked up only once.
+    let users = stub
+        .requests()
+        .iter()
+        .filter(|r| r.api_method() == "users.info")
+        .count();
+    assert_eq!(users, 3);
+}
+
+#[tokio::test]
+async fn resolves_parent_and_replies() {
+    let stub = thread_stub().await;
+    let url = THREAD_URL.parse().unwrap();
+
+    let resolved = session(&stub)
+        .scope(Scope::ParentAndReplies(1))
+        .retriever(&url)
+        .unwrap()
+        .resolve(false)
+        .await
+        .unwrap();
+
+    let authors: Vec<_> = resolved.messages.iter().map(|m| m.user_name.as_str()).collect();
+    assert_eq!(authors, ["u1", "u2"]);
+    let transcript = resolved.transcript();
+    assert!(transcript.starts_with("### u1 ("), "{transcript}");
+    assert!(transcript.contains("\n\n### u2 ("), "{transcript}");
+}
@@ -0,0 +1,83 @@
+//! Retries of rate limited requests, against a local stub server.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{serve, Reply};
+use slack_client::{id::UserId, rate_limit::RetryPolicy, users, ApiClient, ErrorCode, SlackError};
+
+fn client(base_url: String, max_retries: u32) -> ApiClient {
+    ApiClient::builder("xoxb-test")
+        .base_url(base_url)
+        .retry_policy(RetryPolicy {
+            max_retries,
+            default_delay: Duration::from_secs(10),
+        })
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn retries_after_retry_after() {
+    let stub = serve(|_, n| match n {
+        0 => Reply::status("429 Too Many Requests").header("Retry-After", "1"),
+        _ => Reply::ok(),
+    })
+    .await;
+    let id: UserId = "U1".parse().unwrap();
+
+    let started = Instant::now();
+    let response = client(stub.base_url(), 3)
+        .users(&users::Info { id: &id })
+        .await
+        .unwrap();
+    let elapsed = started.elapsed();
+
+    assert!(response.ok);
+    assert_eq!(stub.requests().len(), 2);
+    // Waited for `Retry-After: 1`, not for the default delay of 10 seconds.
+    assert!(elapsed >= Duration::from_secs(1), "{elapsed:?}");
+    assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let stub = serve(|_, _| {
+        Reply::json(r#"{"ok":false,"error":"ratelimited"}"#).header("Retry-After", "0")
+    })
+    .await;
+    let id: UserId = "U1".parse().unwrap();
+
+    let error = client(stub.base_url(), 2)
+        .users(&users::Info { id: &id })
+        .await
+        .unwrap_err();
+
+    let error = error.downcast_ref::<SlackError>().unwrap();
+    assert_eq!(error.code, ErrorCode::Ratelimited);
+    assert_eq!(error.retry_after, Some(Duration::ZERO));
+    assert_eq!(stub.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn no_retries_with_none_policy() {
+    let stub = serve(|_, n| match n {
+        0 => Reply::status("429 Too Many Requests").header("Retry-After", "1"),
+        _ => Reply::ok(),
+    })
+    .await;
+    let id: UserId = "U1".parse().unwrap();
+
+    let error = ApiClient::builder("xoxb-test")
+        .base_url(stub.base_url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+        .users(&users::Info { id: &id })
+        .await
+        .unwrap_err();
+
+    assert!(error.downcast_ref::<SlackError>().unwrap().is_ratelimited());
+    assert_eq!(stub.requests().len(), 1);
+}